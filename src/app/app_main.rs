@@ -1,11 +1,21 @@
 use crate::cli::{self, Cli};
 use clap::Parser;
+use std::path::PathBuf;
 
 /// Top level app.
 /// this holds the implementation for calling all requied top level methods when called from cli.
 pub struct App {
     pub has_error: bool,
+    // set when a runtime error stopped the interpreter.
     pub has_runtime_error: bool,
+    // if calls in tail position are eliminated.
+    pub tail_calls: bool,
+    // limit of nested function calls.
+    pub max_depth: usize,
+    // directory relative paths in scripts are resolved against.
+    pub script_dir: PathBuf,
+    // arguments after `--`, passed to the script as `args`.
+    pub script_args: Vec<String>,
 }
 
 impl App {
@@ -14,6 +24,10 @@ impl App {
         Self {
             has_error: false,
             has_runtime_error: false,
+            tail_calls: true,
+            max_depth: App::DEFAULT_MAX_DEPTH,
+            script_dir: PathBuf::new(),
+            script_args: vec![],
         }
     }
 
@@ -33,11 +47,28 @@ impl App {
             }
 
             // compile.
-            cli::Commands::Run { filepath } => {
+            cli::Commands::Run {
+                filepath,
+                no_tco,
+                max_depth,
+                args,
+            } => {
                 spdlog::debug!("compile command was invoked");
+                self.tail_calls = !no_tco;
+                self.max_depth = max_depth;
+                self.script_args = args;
+                if let Some(script_dir) = filepath.parent() {
+                    self.script_dir = script_dir.to_path_buf();
+                }
                 self.compile_file(&filepath);
             }
 
+            // type check.
+            cli::Commands::Check { filepath } => {
+                spdlog::debug!("check command was invoked");
+                self.check_file(&filepath);
+            }
+
             // docs.
             cli::Commands::Docs => {
                 spdlog::debug!("docs command was invoked");
@@ -0,0 +1,34 @@
+use super::app_main::App;
+use crate::{
+    checker::checker_main::TypeChecker, lexer::lexer_main::Lexer, parser::parser_main::Parser,
+};
+use std::{path::PathBuf, process::exit};
+
+impl App {
+    /// Type checks a file without running it, exits with
+    /// a non zero status if any errors were found.
+    pub fn check_file(&self, filepath: &PathBuf) {
+        let source = App::read_source(filepath);
+        spdlog::info!("Checking : \n{}", source);
+
+        // lexical analysis.
+        let mut lexer = Lexer::new(source, App::get_reserved_keywords());
+        let tokens = lexer.scan_tokens().to_owned();
+
+        let mut parser = Parser::new(tokens);
+        let statements = parser.parse();
+
+        if lexer.has_error || parser.has_error {
+            spdlog::error!("Found parsing errrors, skipping type checking.");
+            exit(1);
+        }
+
+        let mut checker = TypeChecker::new();
+        if !checker.check(&statements) {
+            spdlog::error!("Found type errors.");
+            exit(1);
+        }
+
+        println!("No type errors found.");
+    }
+}
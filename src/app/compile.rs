@@ -1,14 +1,18 @@
 use super::app_main::App;
 use crate::{
-    interpreter::interpreter_main::Interpreter, lexer::lexer_main::Lexer,
+    interpreter::{error::RuntimeError, interpreter_main::Interpreter},
+    lexer::lexer_main::Lexer,
     parser::parser_main::Parser,
 };
+use std::panic::{self, AssertUnwindSafe};
 
 impl App {
     /// Top level compile function, this function
     /// takes source as input string and calls all
     /// the steps required to compile and run it.
-    pub fn compile(&self, source: String) {
+    /// Sets `has_error` for syntax errors and `has_runtime_error`
+    /// if the program was stopped by a runtime error.
+    pub fn compile(&mut self, source: String) {
         spdlog::info!("Compiling : \n{}", source);
 
         // lexical analysis.
@@ -20,12 +24,28 @@ impl App {
         let mut parser = Parser::new(tokens);
         let statements = parser.parse();
 
-        if parser.has_error {
+        if lexer.has_error || parser.has_error {
             spdlog::error!("Found parsing errrors, terminating execution.");
+            self.has_error = true;
             return;
         }
 
-        let mut interpreter = Interpreter::new();
-        interpreter.interpret(statements)
+        let mut interpreter = Interpreter::new(
+            self.tail_calls,
+            self.max_depth,
+            self.script_dir.clone(),
+            self.script_args.clone(),
+        );
+
+        // runtime errors unwind out of the interpreter, they are reported
+        // here if no try statement caught them.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| interpreter.interpret(statements)));
+        if let Err(error) = result {
+            spdlog::error!("Stopped by a runtime error.");
+            if let Some(error) = error.downcast_ref::<RuntimeError>() {
+                App::report(error.line, "".to_string(), error.message.to_owned());
+            }
+            self.has_runtime_error = true;
+        }
     }
 }
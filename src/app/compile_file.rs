@@ -4,8 +4,24 @@ use std::{fs, path::PathBuf, process::exit};
 
 impl App {
     /// compile function for files.
-    /// this is a wrapper for compile function.
-    pub fn compile_file(&self, filepath: &PathBuf) {
+    /// this is a wrapper for compile function, it exits with
+    /// a non zero status if the file had errors.
+    pub fn compile_file(&mut self, filepath: &PathBuf) {
+        let file_contents = App::read_source(filepath);
+
+        self.compile(file_contents);
+
+        if self.has_error {
+            exit(App::EXIT_COMPILE_ERROR);
+        }
+        if self.has_runtime_error {
+            exit(App::EXIT_RUNTIME_ERROR);
+        }
+    }
+
+    /// Reads contents of a source file, exits if the file
+    /// does not exist or could not be read.
+    pub fn read_source(filepath: &PathBuf) -> String {
         // checking for existence of file.
         if !filepath.exists() {
             error!(
@@ -29,10 +45,6 @@ impl App {
             }
         };
 
-        self.compile(file_contents);
-
-        if self.has_error {
-            panic!("Has some error idk");
-        }
+        file_contents
     }
 }
@@ -5,6 +5,29 @@ use crate::token::token_types::TokenType;
 use super::app_main::App;
 
 impl App {
+    /// Default limit of nested function calls.
+    pub const DEFAULT_MAX_DEPTH: usize = 1000;
+
+    /// Stack size of the thread running the app, large enough that the call
+    /// depth limit is reached long before the native stack overflows.
+    pub const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+    /// Native stack a nested call can take, with room for nested blocks
+    /// and expressions in the function's body, even in debug builds.
+    pub const STACK_PER_CALL: usize = 32 * 1024;
+
+    /// Highest limit of nested function calls the thread's stack can hold.
+    pub const MAX_DEPTH_LIMIT: usize = App::STACK_SIZE / App::STACK_PER_CALL;
+
+    /// Exit status of scripts with syntax errors.
+    pub const EXIT_COMPILE_ERROR: i32 = 65;
+
+    /// Exit status of scripts stopped by a runtime error.
+    pub const EXIT_RUNTIME_ERROR: i32 = 70;
+
+    /// Largest string, in bytes, natives are allowed to build.
+    pub const MAX_STRING_LENGTH: usize = 1 << 30;
+
     /// Gets the reserved keywords.
     pub fn get_reserved_keywords() -> HashMap<String, TokenType> {
         spdlog::debug!("generating and returning reserved keywords hashmap.");
@@ -25,6 +48,15 @@ impl App {
             ("while".to_string(), TokenType::While),
             ("for".to_string(), TokenType::For),
             ("print".to_string(), TokenType::Print),
+            ("enum".to_string(), TokenType::Enum),
+            ("match".to_string(), TokenType::Match),
+            ("assert".to_string(), TokenType::Assert),
+            ("break".to_string(), TokenType::Break),
+            ("continue".to_string(), TokenType::Continue),
+            ("do".to_string(), TokenType::Do),
+            ("defer".to_string(), TokenType::Defer),
+            ("try".to_string(), TokenType::Try),
+            ("catch".to_string(), TokenType::Catch),
         ])
     }
 }
@@ -33,6 +33,7 @@ impl App {
 
             source = "".to_string();
             self.has_error = false;
+            self.has_runtime_error = false;
         }
 
         spdlog::debug!("breaking out of the repl loop.")
@@ -1,37 +1,36 @@
 use super::app_main::App;
-use crate::token::{token_main::Token, token_types::TokenType};
-use core::panic;
+use crate::{
+    interpreter::error::RuntimeError,
+    token::{token_main::Token, token_types::TokenType},
+};
 use spdlog::Logger;
-use std::sync::Arc;
+use std::{panic, sync::Arc};
 
 impl App {
     /// Struct method to setup global logging.
+    /// The level can be set with the `SPX_LOG` env variable, without it
+    /// everything is logged in debug mode and nothing in release mode.
     pub fn setup_logging() {
-        if cfg!(debug_assertions) {
-            let default_logger: Arc<Logger> = spdlog::default_logger();
-            default_logger.set_level_filter(spdlog::LevelFilter::All);
-            spdlog::info!("in debug mode, defaulting log level to : All");
-        } else {
-            match spdlog::init_env_level_from("SPX_LOG") {
-                Ok(applied) => {
-                    let default_logger: Arc<Logger> = spdlog::default_logger();
-                    if applied {
-                        spdlog::info!(
-                            "Applied level from env variable to : {:?}",
-                            default_logger.level_filter()
-                        );
-                    } else {
-                        default_logger.set_level_filter(spdlog::LevelFilter::Off);
-                    }
-                }
-                Err(_) => {
-                    let default_logger: Arc<Logger> = spdlog::default_logger();
-                    default_logger.set_level_filter(spdlog::LevelFilter::All);
+        match spdlog::init_env_level_from("SPX_LOG") {
+            Ok(applied) => {
+                let default_logger: Arc<Logger> = spdlog::default_logger();
+                if applied {
                     spdlog::info!(
-                        "Failed to apply log level from env variable defaulting to : All"
+                        "Applied level from env variable to : {:?}",
+                        default_logger.level_filter()
                     );
+                } else if cfg!(debug_assertions) {
+                    default_logger.set_level_filter(spdlog::LevelFilter::All);
+                    spdlog::info!("in debug mode, defaulting log level to : All");
+                } else {
+                    default_logger.set_level_filter(spdlog::LevelFilter::Off);
                 }
             }
+            Err(_) => {
+                let default_logger: Arc<Logger> = spdlog::default_logger();
+                default_logger.set_level_filter(spdlog::LevelFilter::All);
+                spdlog::info!("Failed to apply log level from env variable defaulting to : All");
+            }
         };
     }
 
@@ -66,20 +65,23 @@ impl App {
         println!("[line {}] Error '{}' : {}", line, where_is, message);
     }
 
-    /// Function to show runtime errors and set state.
+    /// Function to raise runtime errors, it unwinds with a `RuntimeError`
+    /// which is reported once it reaches the top of the program uncaught.
     /// # Argument
     /// * `line` - line number where error occured.
     /// * `message` - message for the error.
     pub fn runtime_error(line: usize, message: String) {
         spdlog::error!("App::runtime_error called for line : {line} wht message : {message}");
-        App::report(line, "".to_string(), message.to_string());
-        panic!("[line {}] : {}", line, message);
+        panic::panic_any(RuntimeError::new(line, message));
     }
 
     /// Sets up hook for global panic!().
     pub fn setup_custom_panic() {
-        use std::panic;
         panic::set_hook(Box::new(|panic_info| {
+            // runtime errors can still be caught, they are reported when they aren't.
+            if panic_info.payload().is::<RuntimeError>() {
+                return;
+            }
             if let Some(message) = panic_info.payload().downcast_ref::<&str>() {
                 println!("Runtime panic occured: {message:?}");
             } else {
@@ -51,4 +51,40 @@ impl ExprVisitor<()> for AstPrinter {
     fn visit_call_expr(&mut self, expr: &super::expr_ast::ExprCall) {
         print!("{}", expr.callee);
     }
+
+    fn visit_conditional_expr(&mut self, expr: &super::expr_ast::ExprConditional) {
+        print!(
+            "? {} {} {}",
+            expr.condition, expr.then_branch, expr.else_branch
+        );
+    }
+
+    fn visit_destructure_expr(&mut self, expr: &super::expr_ast::ExprDestructure) {
+        print!("= {} {}", expr.pattern, expr.value);
+    }
+
+    fn visit_get_expr(&mut self, expr: &super::expr_ast::ExprGet) {
+        let dot = if expr.optional { "?." } else { "." };
+        print!("{} {} {}", dot, expr.object, expr.name.lexeme);
+    }
+
+    fn visit_optional_chain_expr(&mut self, expr: &super::expr_ast::ExprOptionalChain) {
+        walk_expr(self, &expr.expression);
+    }
+
+    fn visit_index_expr(&mut self, expr: &super::expr_ast::ExprIndex) {
+        print!("[] {} {}", expr.object, expr.index);
+    }
+
+    fn visit_list_expr(&mut self, expr: &super::expr_ast::ExprList) {
+        print!("[");
+        for element in &expr.elements {
+            walk_expr(self, element);
+        }
+        print!("]");
+    }
+
+    fn visit_spread_expr(&mut self, expr: &super::expr_ast::ExprSpread) {
+        print!("... {}", expr.expression);
+    }
 }
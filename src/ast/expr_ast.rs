@@ -1,13 +1,14 @@
 use crate::{
     app::app_main::App,
     interpreter::{
-        environment::{self, Environment},
+        environment::{self, Environment, SharedEnvironment},
         interpreter_main::Interpreter,
     },
     token::token_main::{Token, TokenLiterals},
 };
 
-use super::stmt_ast::StmtFunc;
+use super::stmt_ast::{ListPattern, StmtFunc};
+use std::{cell::RefCell, rc::Rc};
 
 /// Base Expression enum.
 /// Holds variants for all types of expressions.
@@ -15,26 +16,52 @@ use super::stmt_ast::StmtFunc;
 pub enum Expr {
     Binary(Box<ExprBinary>),
     Call(Box<ExprCall>),
+    Conditional(Box<ExprConditional>),
+    Destructure(Box<ExprDestructure>),
+    Get(Box<ExprGet>),
     Grouping(Box<ExprGrouping>),
+    Index(Box<ExprIndex>),
+    List(Box<ExprList>),
     Literal(Box<ExprLiteral>),
     Logical(Box<ExprLogical>),
+    OptionalChain(Box<ExprOptionalChain>),
+    Spread(Box<ExprSpread>),
     Unary(Box<ExprUnary>),
     Variable(Box<ExprVariable>),
     Assignment(Box<ExprAssign>),
 }
 
-/// display implementation for token expr.
+/// display implementation for token expr, prints the expression as source text.
 impl std::fmt::Display for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Expr::Binary(n) => write!(f, "{}", n),
-            Expr::Call(n) => write!(f, "{}({:?})", n.callee, n.arguments),
+            Expr::Call(n) => {
+                let arguments: Vec<String> = n.arguments.iter().map(|a| a.to_string()).collect();
+                let call = if n.optional { "?.(" } else { "(" };
+                write!(f, "{}{}{})", n.callee, call, arguments.join(", "))
+            }
+            Expr::Conditional(n) => {
+                write!(f, "{} ? {} : {}", n.condition, n.then_branch, n.else_branch)
+            }
+            Expr::Destructure(n) => write!(f, "{} = {}", n.pattern, n.value),
+            Expr::Get(n) => {
+                let dot = if n.optional { "?." } else { "." };
+                write!(f, "{}{}{}", n.object, dot, n.name.lexeme)
+            }
             Expr::Grouping(n) => write!(f, "{}", n),
+            Expr::Index(n) => write!(f, "{}[{}]", n.object, n.index),
+            Expr::List(n) => {
+                let elements: Vec<String> = n.elements.iter().map(|e| e.to_string()).collect();
+                write!(f, "[{}]", elements.join(", "))
+            }
             Expr::Literal(n) => write!(f, "{}", n),
+            Expr::OptionalChain(n) => write!(f, "{}", n.expression),
+            Expr::Spread(n) => write!(f, "...{}", n.expression),
             Expr::Unary(n) => write!(f, "{}", n),
-            Expr::Variable(n) => write!(f, "{}", n.name),
-            Expr::Assignment(n) => write!(f, "{} : {}", n.name, n.value),
-            Expr::Logical(n) => write!(f, "{} {} {}", n.left, n.operator, n.right),
+            Expr::Variable(n) => write!(f, "{}", n),
+            Expr::Assignment(n) => write!(f, "{} = {}", n.name.lexeme, n.value),
+            Expr::Logical(n) => write!(f, "{} {} {}", n.left, n.operator.lexeme, n.right),
         }
     }
 }
@@ -47,12 +74,19 @@ impl std::fmt::Display for Expr {
 pub trait ExprVisitor<T> {
     fn visit_binary_expr(&mut self, expr: &ExprBinary) -> T;
     fn visit_call_expr(&mut self, expr: &ExprCall) -> T;
+    fn visit_conditional_expr(&mut self, expr: &ExprConditional) -> T;
+    fn visit_destructure_expr(&mut self, expr: &ExprDestructure) -> T;
+    fn visit_get_expr(&mut self, expr: &ExprGet) -> T;
+    fn visit_index_expr(&mut self, expr: &ExprIndex) -> T;
+    fn visit_list_expr(&mut self, expr: &ExprList) -> T;
+    fn visit_spread_expr(&mut self, expr: &ExprSpread) -> T;
     fn visit_grouping_expr(&mut self, expr: &ExprGrouping) -> T;
     fn visit_literal_expr(&mut self, expr: &ExprLiteral) -> T;
     fn visit_unary_expr(&mut self, expr: &ExprUnary) -> T;
     fn visit_let_expr(&mut self, expr: &ExprVariable) -> T;
     fn visit_assign_expr(&mut self, expr: &ExprAssign) -> T;
     fn visit_logical_expr(&mut self, expr: &ExprLogical) -> T;
+    fn visit_optional_chain_expr(&mut self, expr: &ExprOptionalChain) -> T;
 }
 
 /// Walker, in other implementation this will be called `accept`.
@@ -69,6 +103,13 @@ pub fn walk_expr<T>(visitor: &mut dyn ExprVisitor<T>, expr: &Expr) -> T {
         Expr::Assignment(e) => visitor.visit_assign_expr(e),
         Expr::Logical(e) => visitor.visit_logical_expr(e),
         Expr::Call(e) => visitor.visit_call_expr(e),
+        Expr::Conditional(e) => visitor.visit_conditional_expr(e),
+        Expr::Destructure(e) => visitor.visit_destructure_expr(e),
+        Expr::Get(e) => visitor.visit_get_expr(e),
+        Expr::Index(e) => visitor.visit_index_expr(e),
+        Expr::List(e) => visitor.visit_list_expr(e),
+        Expr::Spread(e) => visitor.visit_spread_expr(e),
+        Expr::OptionalChain(e) => visitor.visit_optional_chain_expr(e),
     }
 }
 
@@ -86,7 +127,7 @@ pub struct ExprBinary {
 /// display implementation for binary token.
 impl std::fmt::Display for ExprBinary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {} {}", self.operator.lexeme, self.left, self.right)
+        write!(f, "{} {} {}", self.left, self.operator.lexeme, self.right)
     }
 }
 
@@ -100,7 +141,7 @@ pub struct ExprGrouping {
 /// display implementation for grouping token.
 impl std::fmt::Display for ExprGrouping {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "( group {} )", self.expression)
+        write!(f, "({})", self.expression)
     }
 }
 
@@ -114,7 +155,11 @@ pub struct ExprLiteral {
 /// display implementation for literal token.
 impl std::fmt::Display for ExprLiteral {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        match &self.value {
+            // strings are quoted, as they are written in source.
+            TokenLiterals::String(value) => write!(f, "\"{}\"", value),
+            value => write!(f, "{}", value),
+        }
     }
 }
 
@@ -130,7 +175,7 @@ pub struct ExprUnary {
 /// display implementation for unary token.
 impl std::fmt::Display for ExprUnary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.operator, self.right)
+        write!(f, "{}{}", self.operator.lexeme, self.right)
     }
 }
 
@@ -144,7 +189,7 @@ pub struct ExprVariable {
 /// display implementation for unary token.
 impl std::fmt::Display for ExprVariable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name)
+        write!(f, "{}", self.name.lexeme)
     }
 }
 
@@ -177,22 +222,167 @@ pub struct ExprCall {
     pub paren: Token,
     // right hand of operation.
     pub arguments: Vec<Expr>,
+    // called using '?.', evaluates to null when callee is null.
+    pub optional: bool,
+}
+
+/// Grammer for conditional expressions,
+/// both `condition ? a : b` and `if (condition) { a } else { b }`.
+#[derive(Debug, Clone)]
+pub struct ExprConditional {
+    // condition to check.
+    pub condition: Expr,
+    // evaluated when condition is truthy.
+    pub then_branch: Expr,
+    // evaluated when condition is falsy.
+    pub else_branch: Expr,
+}
+
+/// Grammer for destructuring assignments, `[a, b] = [b, a]`.
+#[derive(Debug, Clone)]
+pub struct ExprDestructure {
+    // pattern the value is assigned to, every name in it must already exist.
+    pub pattern: ListPattern,
+    // value being destructured.
+    pub value: Expr,
+}
+
+/// Grammer for property access expressions, `object.name`.
+#[derive(Debug, Clone)]
+pub struct ExprGet {
+    // expression whose property is accessed.
+    pub object: Expr,
+    // name of the property.
+    pub name: Token,
+    // if the access is optional, `object?.name`, which is null when the object is null.
+    pub optional: bool,
+}
+
+/// Grammer for chains of calls, property accesses and indexes containing
+/// a `?.`, like `a?.b.c()`. Once a `?.` short-circuits on null the rest of
+/// the chain is skipped, and the whole chain is null.
+#[derive(Debug, Clone)]
+pub struct ExprOptionalChain {
+    // the outermost link of the chain.
+    pub expression: Expr,
+}
+
+/// Grammer for list literals, `[a, b, ...c]`.
+#[derive(Debug, Clone)]
+pub struct ExprList {
+    // elements of the list, may contain spreads.
+    pub elements: Vec<Expr>,
+}
+
+/// Grammer for index expressions, `object[index]`.
+#[derive(Debug, Clone)]
+pub struct ExprIndex {
+    // expression being indexed.
+    pub object: Expr,
+    // closing ']' token, for error reporting.
+    pub bracket: Token,
+    // the index.
+    pub index: Expr,
+}
+
+/// Grammer for spread expressions, `...list`.
+/// Only valid as call arguments and list elements.
+#[derive(Debug, Clone)]
+pub struct ExprSpread {
+    // the '...' token, for error reporting.
+    pub operator: Token,
+    // list being spread.
+    pub expression: Expr,
 }
 
 // splax callable.
 pub trait SplaxCallable {
-    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<TokenLiterals>);
+    /// Calls with evaluated arguments, `line` is the line of the call.
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals;
 }
 
 #[derive(Debug, Clone)]
 pub struct FunctionObject {
-    pub declaration: StmtFunc,
+    // declaration of the function, shared by every copy of the same function value.
+    pub declaration: Rc<StmtFunc>,
+    // environment the function was defined in, shared with it, so the function
+    // sees and updates its variables even once the scope is gone.
+    pub closure: SharedEnvironment,
 }
 
 impl SplaxCallable for FunctionObject {
-    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<TokenLiterals>) {
-        // create new environment for function.
-        let mut environment = Box::new(Environment::new(Some(interpreter.environment.clone())));
+    /// Calls the function, then keeps calling the functions it tail calls
+    /// in a loop, so tail recursion doesn't grow the stack.
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        if interpreter.call_stack.len() >= interpreter.max_depth {
+            App::runtime_error(
+                line,
+                format!(
+                    "Maximum recursion depth of {} exceeded calling '{}'.\n{}",
+                    interpreter.max_depth,
+                    self.declaration.name.lexeme,
+                    interpreter.stack_trace()
+                ),
+            );
+        }
+        interpreter.call_stack.push(self.declaration.name.clone());
+
+        let mut tail_function: Box<FunctionObject>;
+        let mut function = self;
+        let mut arguments = arguments;
+        let mut line = line;
+
+        loop {
+            let value = function.execute(interpreter, arguments, line);
+            match interpreter.tail_call.take() {
+                Some((next_function, next_arguments, next_line)) => {
+                    spdlog::trace!(
+                        "tail calling '{}' from '{}'",
+                        next_function.declaration.name.lexeme,
+                        function.declaration.name.lexeme
+                    );
+                    // tail calls take the place of the caller on the stack.
+                    if let Some(frame) = interpreter.call_stack.last_mut() {
+                        *frame = next_function.declaration.name.clone();
+                    }
+                    tail_function = next_function;
+                    function = &tail_function;
+                    arguments = next_arguments;
+                    line = next_line;
+                }
+                None => {
+                    interpreter.call_stack.pop();
+                    return value;
+                }
+            }
+        }
+    }
+}
+
+impl FunctionObject {
+    /// Executes the function's body once with the given arguments.
+    /// # Arguments
+    /// * `interpreter` - The interpreter to execute the body in.
+    /// * `arguments` - Evaluated arguments of the call.
+    /// * `line` - line of the call, for errors.
+    pub fn execute(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        // create new environment for function, enclosed by the scope it was defined in.
+        let mut environment = Environment::new(Some(Rc::clone(&self.closure)));
 
         // define function arguments in new environment.
         for (i, arg) in arguments
@@ -206,18 +396,54 @@ impl SplaxCallable for FunctionObject {
             )
         }
 
-        if arguments.len() != self.declaration.params.len() {
-            App::runtime_error(
-                self.declaration.name.line,
-                format!(
-                    "Expected {} arguments got {}.",
-                    self.declaration.params.len(),
-                    arguments.len()
-                ),
-            )
+        match &self.declaration.rest_param {
+            // extra arguments are collected into a list for the rest parameter.
+            Some(rest_param) => {
+                if arguments.len() < self.declaration.params.len() {
+                    App::runtime_error(
+                        line,
+                        format!(
+                            "Expected at least {} arguments got {}.",
+                            self.declaration.params.len(),
+                            arguments.len()
+                        ),
+                    )
+                }
+
+                let rest = arguments[self.declaration.params.len()..].to_vec();
+                environment.define(
+                    rest_param.lexeme.to_string(),
+                    environment::SplaxDeclarations::Literals(Box::new(TokenLiterals::new_list(
+                        rest,
+                    ))),
+                )
+            }
+            None => {
+                if arguments.len() != self.declaration.params.len() {
+                    App::runtime_error(
+                        line,
+                        format!(
+                            "Expected {} arguments got {}.",
+                            self.declaration.params.len(),
+                            arguments.len()
+                        ),
+                    )
+                }
+            }
         }
 
-        // interpret function body.
-        interpreter.execute_block(&self.declaration.body, environment);
+        // interpret function body, deferred statements of the function
+        // are the ones registered after this point, and it starts outside of any try.
+        let frame_base = std::mem::replace(&mut interpreter.frame_base, interpreter.deferred.len());
+        let try_depth = std::mem::replace(&mut interpreter.try_depth, 0);
+        interpreter.execute_block(&self.declaration.body, Rc::new(RefCell::new(environment)));
+        interpreter.frame_base = frame_base;
+        interpreter.try_depth = try_depth;
+
+        // functions without a return statement return null.
+        interpreter
+            .return_value
+            .take()
+            .unwrap_or(TokenLiterals::Null)
     }
 }
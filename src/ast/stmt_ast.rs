@@ -5,12 +5,20 @@ use super::expr_ast::Expr;
 /// Top level statements enum.
 #[derive(Debug, Clone)]
 pub enum Stmt {
+    Assert(Box<StmtAssert>),
     Block(Box<StmtBlock>),
+    Break(Box<StmtBreak>),
+    Continue(Box<StmtContinue>),
+    Defer(Box<StmtDefer>),
+    Enum(Box<StmtEnum>),
     Expr(Box<StmtExpr>),
     Function(Box<StmtFunc>),
     If(Box<StmtIf>),
+    Match(Box<StmtMatch>),
     Print(Box<StmtPrint>),
     Let(Box<StmtLet>),
+    Return(Box<StmtReturn>),
+    Try(Box<StmtTry>),
     While(Box<StmtWhile>),
 }
 
@@ -27,6 +35,14 @@ pub trait StmtVisitor {
     fn visit_if_stmt(&mut self, stmt: &StmtIf);
     fn visit_while_stmt(&mut self, stmt: &StmtWhile);
     fn visit_function_stmt(&mut self, stmt: &StmtFunc);
+    fn visit_return_stmt(&mut self, stmt: &StmtReturn);
+    fn visit_enum_stmt(&mut self, stmt: &StmtEnum);
+    fn visit_match_stmt(&mut self, stmt: &StmtMatch);
+    fn visit_assert_stmt(&mut self, stmt: &StmtAssert);
+    fn visit_break_stmt(&mut self, stmt: &StmtBreak);
+    fn visit_continue_stmt(&mut self, stmt: &StmtContinue);
+    fn visit_defer_stmt(&mut self, stmt: &StmtDefer);
+    fn visit_try_stmt(&mut self, stmt: &StmtTry);
 }
 
 /// Walker, in other implementation this will be called `accept`.
@@ -42,6 +58,14 @@ pub fn walk_stmt(visitor: &mut dyn StmtVisitor, stmt: &Stmt) {
         Stmt::If(stmt) => visitor.visit_if_stmt(stmt),
         Stmt::While(stmt) => visitor.visit_while_stmt(stmt),
         Stmt::Function(stmt) => visitor.visit_function_stmt(stmt),
+        Stmt::Return(stmt) => visitor.visit_return_stmt(stmt),
+        Stmt::Enum(stmt) => visitor.visit_enum_stmt(stmt),
+        Stmt::Match(stmt) => visitor.visit_match_stmt(stmt),
+        Stmt::Assert(stmt) => visitor.visit_assert_stmt(stmt),
+        Stmt::Break(stmt) => visitor.visit_break_stmt(stmt),
+        Stmt::Continue(stmt) => visitor.visit_continue_stmt(stmt),
+        Stmt::Defer(stmt) => visitor.visit_defer_stmt(stmt),
+        Stmt::Try(stmt) => visitor.visit_try_stmt(stmt),
     }
 }
 
@@ -77,11 +101,46 @@ pub struct StmtPrint {
     pub expr: Expr,
 }
 
+/// Grammer for stmtassert statemments.
+#[derive(Debug, Clone)]
+pub struct StmtAssert {
+    // the assert keyword, for error reporting.
+    pub keyword: Token,
+    // condition which must be true.
+    pub condition: Expr,
+    // optional message shown when the assertion fails.
+    pub message: Option<Expr>,
+}
+
+/// Grammer for stmtdefer statemments.
+#[derive(Debug, Clone)]
+pub struct StmtDefer {
+    // the defer keyword.
+    pub keyword: Token,
+    // statement to execute when the enclosing block exits.
+    pub body: Stmt,
+}
+
+/// Grammer for stmttry statemments.
+#[derive(Debug, Clone)]
+pub struct StmtTry {
+    // the try keyword.
+    pub keyword: Token,
+    // statements whose runtime errors are caught.
+    pub body: StmtBlock,
+    // name the error message is bound to in the handler.
+    pub name: Token,
+    // statements executed when the body raised a runtime error.
+    pub handler: StmtBlock,
+}
+
 /// Grammer for stmtlet statemments.
 #[derive(Debug, Clone)]
 pub struct StmtLet {
-    // name of the binding.
-    pub name: Token,
+    // name of the binding, or a list pattern to destructure the value into.
+    pub pattern: BindingPattern,
+    // optional type annotation, `let name: type`.
+    pub type_annotation: Option<Token>,
     // init expr value of the binding.
     pub initialiser: Expr,
 }
@@ -93,6 +152,30 @@ pub struct StmtWhile {
     pub condition: Expr,
     // then branch of while statement.
     pub body: Stmt,
+    // increment of desugared for loops, runs after every iteration.
+    pub increment: Option<Expr>,
+    // optional label, for break and continue.
+    pub label: Option<Token>,
+    // if the body runs once before the condition is checked.
+    pub do_while: bool,
+}
+
+/// Grammer for stmtbreak statemments.
+#[derive(Debug, Clone)]
+pub struct StmtBreak {
+    // the break keyword.
+    pub keyword: Token,
+    // optional label of the loop to break out of.
+    pub label: Option<Token>,
+}
+
+/// Grammer for stmtcontinue statemments.
+#[derive(Debug, Clone)]
+pub struct StmtContinue {
+    // the continue keyword.
+    pub keyword: Token,
+    // optional label of the loop to continue.
+    pub label: Option<Token>,
 }
 
 /// Grammer for function declaration.
@@ -102,6 +185,152 @@ pub struct StmtFunc {
     pub name: Token,
     // parameters of the function
     pub params: Vec<Token>,
+    // optional type annotations of the parameters, one per parameter.
+    pub param_types: Vec<Option<Token>>,
+    // optional return type annotation, `-> type`.
+    pub return_type: Option<Token>,
+    // optional rest parameter, `...name`, collects extra arguments into a list.
+    pub rest_param: Option<Token>,
+    // decorators, `@expression`, applied to the function when it is defined.
+    pub decorators: Vec<Expr>,
     // function body
     pub body: StmtBlock,
 }
+
+/// Grammer for return statements.
+#[derive(Debug, Clone)]
+pub struct StmtReturn {
+    // the 'return' keyword token, for error reporting.
+    pub keyword: Token,
+    // the value to return, null if not given.
+    pub value: Expr,
+}
+
+/// Grammer for enum declarations.
+#[derive(Debug, Clone)]
+pub struct StmtEnum {
+    // name of the enum.
+    pub name: Token,
+    // variants of the enum.
+    pub variants: Vec<EnumVariant>,
+}
+
+/// A single variant of an enum declaration.
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    // name of the variant.
+    pub name: Token,
+    // names of the variant's fields.
+    pub fields: Vec<Token>,
+}
+
+/// Grammer for match statements.
+#[derive(Debug, Clone)]
+pub struct StmtMatch {
+    // the 'match' keyword token, for error reporting.
+    pub keyword: Token,
+    // value being matched.
+    pub subject: Expr,
+    // arms, checked in order.
+    pub arms: Vec<MatchArm>,
+}
+
+/// A single arm of a match statement.
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    // pattern to match the subject against.
+    pub pattern: MatchPattern,
+    // statement executed when the pattern matches.
+    pub body: Stmt,
+}
+
+/// Patterns of match arms.
+#[derive(Debug, Clone)]
+pub enum MatchPattern {
+    // `_`, matches any value.
+    Wildcard,
+    // `Enum.Variant(bindings)`, matches values of that variant and binds its fields.
+    Variant {
+        enum_name: Token,
+        variant: Token,
+        bindings: Vec<Token>,
+    },
+}
+
+/// Patterns bound by let statements and destructuring assignments.
+#[derive(Debug, Clone)]
+pub enum BindingPattern {
+    // a single name, `a`.
+    Name(Token),
+    // elements of a list, `[a, [b, c], d = 1, ...rest]`.
+    List(Box<ListPattern>),
+}
+
+/// Grammer for list patterns.
+#[derive(Debug, Clone)]
+pub struct ListPattern {
+    // the opening bracket, for error reporting.
+    pub bracket: Token,
+    // patterns of the elements, in order.
+    pub elements: Vec<PatternElement>,
+    // optional rest binding, `...name`, collects the remaining elements into a list.
+    pub rest: Option<Token>,
+}
+
+/// An element of a list pattern, with the value used when the list is too short.
+#[derive(Debug, Clone)]
+pub struct PatternElement {
+    pub pattern: BindingPattern,
+    pub default: Option<Expr>,
+}
+
+impl BindingPattern {
+    /// Returns the names bound by the pattern, in order.
+    pub fn names(&self) -> Vec<&Token> {
+        match self {
+            BindingPattern::Name(name) => vec![name],
+            BindingPattern::List(list) => list.names(),
+        }
+    }
+}
+
+impl ListPattern {
+    /// Returns the names bound by the pattern, in order.
+    pub fn names(&self) -> Vec<&Token> {
+        let mut names: Vec<&Token> = self
+            .elements
+            .iter()
+            .flat_map(|element| element.pattern.names())
+            .collect();
+        names.extend(&self.rest);
+        names
+    }
+}
+
+/// display implementation for patterns, prints the pattern as source text.
+impl std::fmt::Display for BindingPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindingPattern::Name(name) => write!(f, "{}", name.lexeme),
+            BindingPattern::List(list) => write!(f, "{}", list),
+        }
+    }
+}
+
+/// display implementation for list patterns, prints the pattern as source text.
+impl std::fmt::Display for ListPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut elements: Vec<String> = self
+            .elements
+            .iter()
+            .map(|element| match &element.default {
+                Some(default) => format!("{} = {}", element.pattern, default),
+                None => element.pattern.to_string(),
+            })
+            .collect();
+        if let Some(rest) = &self.rest {
+            elements.push(format!("...{}", rest.lexeme));
+        }
+        write!(f, "[{}]", elements.join(", "))
+    }
+}
@@ -0,0 +1,26 @@
+use super::types::SplaxType;
+use std::collections::HashMap;
+
+/// A variable known to the checker.
+#[derive(Debug, Clone)]
+pub struct Binding {
+    // type from the annotation, None if the variable is unannotated.
+    pub declared: Option<SplaxType>,
+    // type inferred from the values assigned so far.
+    pub inferred: SplaxType,
+}
+
+/// Top level static type checker struct.
+/// Walks the statements before they are run and reports type mismatches.
+pub struct TypeChecker {
+    // stack of scopes, innermost scope is the last one.
+    pub scopes: Vec<HashMap<String, Binding>>,
+    // index of the first scope of the function being checked.
+    pub function_scope: usize,
+    // name and return type of the function being checked.
+    pub function_return: Option<(String, SplaxType)>,
+    // declared enums, with their variants and number of fields.
+    pub enums: HashMap<String, HashMap<String, usize>>,
+    // stores if there were any type errors.
+    pub has_error: bool,
+}
@@ -0,0 +1,417 @@
+use super::{checker_main::TypeChecker, types::SplaxType};
+use crate::{
+    ast::{
+        self,
+        expr_ast::{walk_expr, Expr, ExprVisitor},
+    },
+    token::{token_main::Token, token_types::TokenType},
+};
+
+/// Impl Visitor pattern for TypeChecker, every expression evaluates to its type.
+impl ExprVisitor<SplaxType> for TypeChecker {
+    /// checks assignments against the variable's annotation.
+    /// # Arguments
+    /// * `expr` - Assignment expression.
+    fn visit_assign_expr(&mut self, expr: &ast::expr_ast::ExprAssign) -> SplaxType {
+        let value = walk_expr(self, &expr.value);
+
+        let mut mismatch = None;
+        if let Some((binding, _)) = self.lookup(&expr.name.lexeme) {
+            match &binding.declared {
+                Some(declared) if !declared.accepts(&value) => mismatch = Some(declared.clone()),
+                Some(_) => (),
+                // unannotated variables are dynamic, once they hold values
+                // of different types nothing is known about them.
+                None => {
+                    if binding.inferred != value {
+                        binding.inferred = SplaxType::Any;
+                    }
+                }
+            }
+        }
+
+        if let Some(declared) = mismatch {
+            self.report(
+                expr.name.line,
+                format!(
+                    "'{}' is declared as '{}' but assigned a '{}'.",
+                    expr.name.lexeme, declared, value
+                ),
+            );
+        }
+
+        value
+    }
+
+    /// checks operand types of binary expressions.
+    /// # Arguments
+    /// * `expr` - Binary Expression.
+    fn visit_binary_expr(&mut self, expr: &ast::expr_ast::ExprBinary) -> SplaxType {
+        let left = walk_expr(self, &expr.left);
+        let right = walk_expr(self, &expr.right);
+        let operator = &expr.operator;
+
+        match operator.token_type {
+            // equality is defined for every type.
+            TokenType::EqualEqual | TokenType::BangEqual => SplaxType::Boolean,
+
+            // numbers or strings, both of the same type.
+            TokenType::Greater
+            | TokenType::GreaterEqual
+            | TokenType::Less
+            | TokenType::LessEqual => {
+                if !TypeChecker::same_orderable(&left, &right) {
+                    self.report(
+                        operator.line,
+                        format!(
+                            "Cannot compare '{}' with '{}' using '{}'.",
+                            left, right, operator.lexeme
+                        ),
+                    );
+                }
+                SplaxType::Boolean
+            }
+
+            // two numbers or two strings.
+            TokenType::Plus => match (&left, &right) {
+                (SplaxType::Number, SplaxType::Number | SplaxType::Any)
+                | (SplaxType::Any, SplaxType::Number) => SplaxType::Number,
+                (SplaxType::String, SplaxType::String | SplaxType::Any)
+                | (SplaxType::Any, SplaxType::String) => SplaxType::String,
+                (SplaxType::Any, SplaxType::Any) => SplaxType::Any,
+                _ => {
+                    self.report(
+                        operator.line,
+                        format!(
+                            "Operator '+' expects two numbers or two strings, found '{}' and '{}'.",
+                            left, right
+                        ),
+                    );
+                    SplaxType::Any
+                }
+            },
+
+            // all other operators work on numbers.
+            _ => {
+                self.expect_number(operator, &left);
+                self.expect_number(operator, &right);
+                SplaxType::Number
+            }
+        }
+    }
+
+    /// checks arguments of calls against the function's signature.
+    /// # Arguments
+    /// * `expr` - Call expression.
+    fn visit_call_expr(&mut self, expr: &ast::expr_ast::ExprCall) -> SplaxType {
+        let mut arguments = vec![];
+        for argument in &expr.arguments {
+            arguments.push(walk_expr(self, argument));
+        }
+
+        // the number of arguments is only known statically without spreads,
+        // arguments after the first spread are not checked against parameters.
+        let spread = expr
+            .arguments
+            .iter()
+            .position(|argument| matches!(argument, Expr::Spread(_)));
+        if let Some(spread) = spread {
+            arguments.truncate(spread);
+        }
+
+        // enum variant constructors.
+        if let Expr::Get(get) = &expr.callee {
+            let count = spread.is_none().then_some(arguments.len());
+            if let Some(enum_type) = self.check_variant(get, count) {
+                return enum_type;
+            }
+        }
+
+        let callee = walk_expr(self, &expr.callee);
+        let SplaxType::Function(function) = callee else {
+            return SplaxType::Any;
+        };
+
+        let arity_mismatch = match (spread, function.rest) {
+            (Some(_), _) => false,
+            (None, true) => arguments.len() < function.params.len(),
+            (None, false) => arguments.len() != function.params.len(),
+        };
+        if arity_mismatch {
+            self.report(
+                expr.paren.line,
+                format!(
+                    "'{}' expects {}{} arguments got {}.",
+                    function.name,
+                    if function.rest { "at least " } else { "" },
+                    function.params.len(),
+                    arguments.len()
+                ),
+            );
+        }
+
+        for (index, (param, argument)) in function.params.iter().zip(arguments).enumerate() {
+            if !param.accepts(&argument) {
+                self.report(
+                    expr.paren.line,
+                    format!(
+                        "Argument {} of '{}' expects a '{}' but got a '{}'.",
+                        index + 1,
+                        function.name,
+                        param,
+                        argument
+                    ),
+                );
+            }
+        }
+
+        function.returns
+    }
+
+    /// type of the branches of conditional expressions.
+    /// # Arguments
+    /// * `expr` - Conditional expression.
+    fn visit_conditional_expr(&mut self, expr: &ast::expr_ast::ExprConditional) -> SplaxType {
+        walk_expr(self, &expr.condition);
+        let then_branch = walk_expr(self, &expr.then_branch);
+        let else_branch = walk_expr(self, &expr.else_branch);
+        TypeChecker::join(then_branch, else_branch)
+    }
+
+    /// checks destructuring assignments, the assigned names can't be
+    /// annotated with types other than `any`, since list elements aren't typed.
+    /// # Arguments
+    /// * `expr` - Destructure expression.
+    fn visit_destructure_expr(&mut self, expr: &ast::expr_ast::ExprDestructure) -> SplaxType {
+        let value = walk_expr(self, &expr.value);
+        self.check_list_pattern(&expr.pattern, &value);
+
+        for name in expr.pattern.names() {
+            let mut mismatch = None;
+            if let Some((binding, _)) = self.lookup(&name.lexeme) {
+                match &binding.declared {
+                    Some(SplaxType::Any) => (),
+                    Some(declared) => mismatch = Some(declared.clone()),
+                    None => binding.inferred = SplaxType::Any,
+                }
+            }
+
+            if let Some(declared) = mismatch {
+                self.report(
+                    name.line,
+                    format!(
+                        "'{}' is declared as '{}' but assigned an untyped list element.",
+                        name.lexeme, declared
+                    ),
+                );
+            }
+        }
+
+        value
+    }
+
+    /// type of optional chains, the type of their last link.
+    /// # Arguments
+    /// * `expr` - Optional chain expression.
+    fn visit_optional_chain_expr(&mut self, expr: &ast::expr_ast::ExprOptionalChain) -> SplaxType {
+        walk_expr(self, &expr.expression)
+    }
+
+    /// only enum variants can be accessed.
+    /// # Arguments
+    /// * `expr` - Get expression.
+    fn visit_get_expr(&mut self, expr: &ast::expr_ast::ExprGet) -> SplaxType {
+        match self.check_variant(expr, Some(0)) {
+            Some(enum_type) => enum_type,
+            None => SplaxType::Any,
+        }
+    }
+
+    /// checks the elements of list literals, spreads must be lists.
+    /// # Arguments
+    /// * `expr` - List expression.
+    fn visit_list_expr(&mut self, expr: &ast::expr_ast::ExprList) -> SplaxType {
+        for element in &expr.elements {
+            walk_expr(self, element);
+        }
+        SplaxType::List
+    }
+
+    /// checks that only lists and strings are indexed, with numbers.
+    /// elements of lists are not typed, so indexing them is `any`,
+    /// characters of strings are strings.
+    /// # Arguments
+    /// * `expr` - Index expression.
+    fn visit_index_expr(&mut self, expr: &ast::expr_ast::ExprIndex) -> SplaxType {
+        let object = walk_expr(self, &expr.object);
+        let index = walk_expr(self, &expr.index);
+
+        if !SplaxType::List.accepts(&object) && !SplaxType::String.accepts(&object) {
+            self.report(
+                expr.bracket.line,
+                format!(
+                    "Can't index a '{}', only lists and strings can be indexed.",
+                    object
+                ),
+            );
+        }
+        if !SplaxType::Number.accepts(&index) {
+            self.report(
+                expr.bracket.line,
+                format!("Index must be a 'number', found a '{}'.", index),
+            );
+        }
+
+        match object {
+            SplaxType::String => SplaxType::String,
+            _ => SplaxType::Any,
+        }
+    }
+
+    /// checks that only lists are spread.
+    /// # Arguments
+    /// * `expr` - Spread expression.
+    fn visit_spread_expr(&mut self, expr: &ast::expr_ast::ExprSpread) -> SplaxType {
+        let value = walk_expr(self, &expr.expression);
+        if !SplaxType::List.accepts(&value) {
+            self.report(
+                expr.operator.line,
+                format!("Can only spread a 'list', found a '{}'.", value),
+            );
+        }
+        SplaxType::Any
+    }
+
+    /// type of the grouped expression.
+    /// # Arguments
+    /// * `expr` - Grouping expression.
+    fn visit_grouping_expr(&mut self, expr: &ast::expr_ast::ExprGrouping) -> SplaxType {
+        walk_expr(self, &expr.expression)
+    }
+
+    /// type of literals.
+    /// # Arguments
+    /// * `expr` - Literal expression.
+    fn visit_literal_expr(&mut self, expr: &ast::expr_ast::ExprLiteral) -> SplaxType {
+        TypeChecker::literal_type(&expr.value)
+    }
+
+    /// checks operands of unary expressions.
+    /// # Arguments
+    /// * `expr` - Unary expression.
+    fn visit_unary_expr(&mut self, expr: &ast::expr_ast::ExprUnary) -> SplaxType {
+        let right = walk_expr(self, &expr.right);
+        match expr.operator.token_type {
+            TokenType::Minus => {
+                self.expect_number(&expr.operator, &right);
+                SplaxType::Number
+            }
+            _ => SplaxType::Boolean,
+        }
+    }
+
+    /// type of variables.
+    /// # Arguments
+    /// * `expr` - Variable expression.
+    fn visit_let_expr(&mut self, expr: &ast::expr_ast::ExprVariable) -> SplaxType {
+        self.type_of(&expr.name.lexeme)
+    }
+
+    /// type of logical expressions.
+    /// # Arguments
+    /// * `expr` - Logical expression.
+    fn visit_logical_expr(&mut self, expr: &ast::expr_ast::ExprLogical) -> SplaxType {
+        let left = walk_expr(self, &expr.left);
+        let right = walk_expr(self, &expr.right);
+
+        // `null ?? b` is always b.
+        if expr.operator.token_type == TokenType::QuestionQuestion && left == SplaxType::Null {
+            return right;
+        }
+
+        TypeChecker::join(left, right)
+    }
+}
+
+impl TypeChecker {
+    /// Reports an error if an operand is known not to be a number.
+    /// # Arguments
+    /// * `operator` - the operator token.
+    /// * `operand` - type of the operand.
+    pub fn expect_number(&mut self, operator: &Token, operand: &SplaxType) {
+        if !matches!(operand, SplaxType::Number | SplaxType::Any) {
+            self.report(
+                operator.line,
+                format!(
+                    "Operator '{}' expects a 'number', found a '{}'.",
+                    operator.lexeme, operand
+                ),
+            );
+        }
+    }
+
+    /// Checks if two types can be ordered, both numbers or both strings.
+    /// Unknown types might be, so they are allowed.
+    /// # Arguments
+    /// * `left` - type of the left operand.
+    /// * `right` - type of the right operand.
+    pub fn same_orderable(left: &SplaxType, right: &SplaxType) -> bool {
+        match (left, right) {
+            (SplaxType::Any, SplaxType::Any) => true,
+            (SplaxType::Any, other) | (other, SplaxType::Any) => {
+                matches!(other, SplaxType::Number | SplaxType::String)
+            }
+            _ => left == right && matches!(left, SplaxType::Number | SplaxType::String),
+        }
+    }
+
+    /// Type of an expression which evaluates to either of two types.
+    /// # Arguments
+    /// * `left` - first type.
+    /// * `right` - second type.
+    pub fn join(left: SplaxType, right: SplaxType) -> SplaxType {
+        if left == right {
+            return left;
+        }
+        SplaxType::Any
+    }
+
+    /// Checks access to an enum's variant, returns the enum's type if
+    /// the expression is accessing an enum.
+    /// # Arguments
+    /// * `expr` - the get expression.
+    /// * `arguments` - number of arguments, `None` if not known statically.
+    pub fn check_variant(
+        &mut self,
+        expr: &ast::expr_ast::ExprGet,
+        arguments: Option<usize>,
+    ) -> Option<SplaxType> {
+        let Expr::Variable(object) = &expr.object else {
+            return None;
+        };
+        let variants = self.enums.get(&object.name.lexeme)?.clone();
+
+        match variants.get(&expr.name.lexeme) {
+            Some(fields) if arguments.is_some_and(|arguments| arguments != *fields) => self.report(
+                expr.name.line,
+                format!(
+                    "'{}.{}' expects {} arguments got {}.",
+                    object.name.lexeme,
+                    expr.name.lexeme,
+                    fields,
+                    arguments.unwrap_or_default()
+                ),
+            ),
+            Some(_) => (),
+            None => self.report(
+                expr.name.line,
+                format!(
+                    "'{}' has no variant '{}'.",
+                    object.name.lexeme, expr.name.lexeme
+                ),
+            ),
+        }
+
+        Some(SplaxType::Enum(object.name.lexeme.to_owned()))
+    }
+}
@@ -0,0 +1,273 @@
+use super::{
+    checker_main::{Binding, TypeChecker},
+    types::{FunctionType, SplaxType},
+};
+use crate::ast::{
+    self,
+    expr_ast::walk_expr,
+    stmt_ast::{walk_stmt, BindingPattern, MatchPattern, StmtVisitor},
+};
+use std::collections::HashMap;
+
+/// Impl StmtVisitor pattern for TypeChecker.
+impl StmtVisitor for TypeChecker {
+    /// checks a block of statements in a new scope.
+    /// # Arguments
+    /// * `stmt` - stmtblock stmt to check.
+    fn visit_block_stmt(&mut self, stmt: &ast::stmt_ast::StmtBlock) {
+        self.scopes.push(HashMap::new());
+        for statement in &stmt.block_statements {
+            walk_stmt(self, statement);
+        }
+        self.scopes.pop();
+    }
+
+    /// checks expression statements.
+    /// # Arguments
+    /// * `stmt` - stmtexpr stmt to check.
+    fn visit_expression_stmt(&mut self, stmt: &ast::stmt_ast::StmtExpr) {
+        walk_expr(self, &stmt.expr);
+    }
+
+    /// checks print statements, any type can be printed.
+    /// # Arguments
+    /// * `stmt` - stmtprint stmt to check.
+    fn visit_print_stmt(&mut self, stmt: &ast::stmt_ast::StmtPrint) {
+        walk_expr(self, &stmt.expr);
+    }
+
+    /// checks let statements against their annotation, and defines the variable.
+    /// # Arguments
+    /// * `stmt` - stmtlet stmt to check.
+    fn visit_let_stmt(&mut self, stmt: &ast::stmt_ast::StmtLet) {
+        let value = walk_expr(self, &stmt.initialiser);
+
+        // destructured names are elements of a list, which aren't typed.
+        let name = match &stmt.pattern {
+            BindingPattern::Name(name) => name,
+            BindingPattern::List(list) => {
+                self.check_list_pattern(list, &value);
+                for name in stmt.pattern.names() {
+                    self.define(
+                        name.lexeme.to_owned(),
+                        Binding {
+                            declared: None,
+                            inferred: SplaxType::Any,
+                        },
+                    );
+                }
+                return;
+            }
+        };
+
+        let mut declared = None;
+        if let Some(annotation) = &stmt.type_annotation {
+            let annotation = self.resolve_type(annotation);
+            if !annotation.accepts(&value) {
+                self.report(
+                    name.line,
+                    format!(
+                        "'{}' is declared as '{}' but initialised with a '{}'.",
+                        name.lexeme, annotation, value
+                    ),
+                );
+            }
+            declared = Some(annotation);
+        }
+
+        self.define(
+            name.lexeme.to_owned(),
+            Binding {
+                declared,
+                inferred: value,
+            },
+        );
+    }
+
+    /// checks both branches of if statements.
+    /// # Arguments
+    /// * `stmt` - stmtif stmt to check.
+    fn visit_if_stmt(&mut self, stmt: &ast::stmt_ast::StmtIf) {
+        walk_expr(self, &stmt.condition);
+        walk_stmt(self, &stmt.then_branch);
+        if let Ok(else_branch) = &stmt.else_branch {
+            walk_stmt(self, else_branch);
+        }
+    }
+
+    /// checks while statements.
+    /// # Arguments
+    /// * `stmt` - stmtwhile stmt to check.
+    fn visit_while_stmt(&mut self, stmt: &ast::stmt_ast::StmtWhile) {
+        walk_expr(self, &stmt.condition);
+        walk_stmt(self, &stmt.body);
+        if let Some(increment) = &stmt.increment {
+            walk_expr(self, increment);
+        }
+    }
+
+    /// checks the deferred statement.
+    /// # Arguments
+    /// * `stmt` - stmtdefer stmt to check.
+    fn visit_defer_stmt(&mut self, stmt: &ast::stmt_ast::StmtDefer) {
+        walk_stmt(self, &stmt.body);
+    }
+
+    /// nothing to check in break statements.
+    fn visit_break_stmt(&mut self, _stmt: &ast::stmt_ast::StmtBreak) {}
+
+    /// nothing to check in continue statements.
+    fn visit_continue_stmt(&mut self, _stmt: &ast::stmt_ast::StmtContinue) {}
+
+    /// defines the function's signature and checks its body.
+    /// # Arguments
+    /// * `stmt` - stmtfunc stmt to check.
+    fn visit_function_stmt(&mut self, stmt: &ast::stmt_ast::StmtFunc) {
+        let mut params = vec![];
+        for param_type in &stmt.param_types {
+            params.push(self.resolve_optional_type(param_type));
+        }
+        let returns = self.resolve_optional_type(&stmt.return_type);
+
+        // defined before checking the body, so recursive calls are checked too.
+        // decorators can replace the function with anything, so nothing is known
+        // about decorated functions.
+        let mut function = SplaxType::Function(Box::new(FunctionType {
+            name: stmt.name.lexeme.to_owned(),
+            params: params.clone(),
+            rest: stmt.rest_param.is_some(),
+            returns: returns.clone(),
+        }));
+        for decorator in &stmt.decorators {
+            walk_expr(self, decorator);
+            function = SplaxType::Any;
+        }
+        self.define(
+            stmt.name.lexeme.to_owned(),
+            Binding {
+                declared: None,
+                inferred: function,
+            },
+        );
+
+        // parameters live in the function's own scope.
+        self.scopes.push(HashMap::new());
+        for (param, param_type) in stmt.params.iter().zip(params) {
+            self.define(
+                param.lexeme.to_owned(),
+                Binding {
+                    declared: Some(param_type.clone()),
+                    inferred: param_type,
+                },
+            );
+        }
+
+        if let Some(rest_param) = &stmt.rest_param {
+            self.define(
+                rest_param.lexeme.to_owned(),
+                Binding {
+                    declared: Some(SplaxType::List),
+                    inferred: SplaxType::List,
+                },
+            );
+        }
+
+        let enclosing_scope = self.function_scope;
+        let enclosing_return = self.function_return.take();
+        self.function_scope = self.scopes.len() - 1;
+        self.function_return = Some((stmt.name.lexeme.to_owned(), returns));
+
+        for statement in &stmt.body.block_statements {
+            walk_stmt(self, statement);
+        }
+
+        self.function_scope = enclosing_scope;
+        self.function_return = enclosing_return;
+        self.scopes.pop();
+    }
+
+    /// checks returned values against the function's return type.
+    /// # Arguments
+    /// * `stmt` - stmtreturn stmt to check.
+    fn visit_return_stmt(&mut self, stmt: &ast::stmt_ast::StmtReturn) {
+        let value = walk_expr(self, &stmt.value);
+        if let Some((name, returns)) = self.function_return.clone() {
+            if !returns.accepts(&value) {
+                self.report(
+                    stmt.keyword.line,
+                    format!(
+                        "'{}' is declared to return '{}' but returns a '{}'.",
+                        name, returns, value
+                    ),
+                );
+            }
+        }
+    }
+
+    /// registers enums, so they can be used in annotations and their
+    /// constructors can be checked.
+    /// # Arguments
+    /// * `stmt` - stmtenum stmt to check.
+    fn visit_enum_stmt(&mut self, stmt: &ast::stmt_ast::StmtEnum) {
+        let variants = stmt
+            .variants
+            .iter()
+            .map(|variant| (variant.name.lexeme.to_owned(), variant.fields.len()))
+            .collect();
+        self.enums.insert(stmt.name.lexeme.to_owned(), variants);
+    }
+
+    /// checks every arm of match statements, pattern bindings are `any`.
+    /// # Arguments
+    /// * `stmt` - stmtmatch stmt to check.
+    fn visit_match_stmt(&mut self, stmt: &ast::stmt_ast::StmtMatch) {
+        walk_expr(self, &stmt.subject);
+
+        for arm in &stmt.arms {
+            self.scopes.push(HashMap::new());
+            if let MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                for binding in bindings {
+                    self.define(
+                        binding.lexeme.to_owned(),
+                        Binding {
+                            declared: None,
+                            inferred: SplaxType::Any,
+                        },
+                    );
+                }
+            }
+            walk_stmt(self, &arm.body);
+            self.scopes.pop();
+        }
+    }
+
+    /// checks the condition and message of assertions.
+    /// # Arguments
+    /// * `stmt` - stmtassert stmt to check.
+    fn visit_assert_stmt(&mut self, stmt: &ast::stmt_ast::StmtAssert) {
+        walk_expr(self, &stmt.condition);
+        if let Some(message) = &stmt.message {
+            walk_expr(self, message);
+        }
+    }
+
+    /// checks both blocks of try statements, the error is a string in the handler.
+    /// # Arguments
+    /// * `stmt` - stmttry stmt to check.
+    fn visit_try_stmt(&mut self, stmt: &ast::stmt_ast::StmtTry) {
+        self.visit_block_stmt(&stmt.body);
+
+        self.scopes.push(HashMap::new());
+        self.define(
+            stmt.name.lexeme.to_owned(),
+            Binding {
+                declared: None,
+                inferred: SplaxType::String,
+            },
+        );
+        for statement in &stmt.handler.block_statements {
+            walk_stmt(self, statement);
+        }
+        self.scopes.pop();
+    }
+}
@@ -0,0 +1,59 @@
+/// Static types known to the checker.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SplaxType {
+    // type is not known statically, compatible with every other type.
+    Any,
+    Number,
+    String,
+    Boolean,
+    Null,
+    List,
+    // values of the enum with the given name.
+    Enum(String),
+    // functions, with their signature.
+    Function(Box<FunctionType>),
+}
+
+/// Signature of a function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionType {
+    // name of the function, for error messages.
+    pub name: String,
+    // types of the parameters, `Any` for unannotated ones.
+    pub params: Vec<SplaxType>,
+    // if extra arguments are collected by a rest parameter.
+    pub rest: bool,
+    // return type, `Any` if unannotated.
+    pub returns: SplaxType,
+}
+
+/// display implementation for types, same as their names in annotations.
+impl std::fmt::Display for SplaxType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplaxType::Any => write!(f, "any"),
+            SplaxType::Number => write!(f, "number"),
+            SplaxType::String => write!(f, "string"),
+            SplaxType::Boolean => write!(f, "boolean"),
+            SplaxType::Null => write!(f, "null"),
+            SplaxType::List => write!(f, "list"),
+            SplaxType::Enum(name) => write!(f, "{}", name),
+            SplaxType::Function(_) => write!(f, "fn"),
+        }
+    }
+}
+
+impl SplaxType {
+    /// Checks if a value of type `actual` can be used where `self` is expected.
+    /// `Any` is compatible both ways, and `null` can be used for every type
+    /// since uninitialised variables are `null`.
+    /// # Arguments
+    /// * `actual` - type of the value being used.
+    pub fn accepts(&self, actual: &SplaxType) -> bool {
+        match (self, actual) {
+            (SplaxType::Any, _) | (_, SplaxType::Any) | (_, SplaxType::Null) => true,
+            (SplaxType::Function(_), SplaxType::Function(_)) => true,
+            _ => self == actual,
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use super::{
+    checker_main::{Binding, TypeChecker},
+    types::{FunctionType, SplaxType},
+};
+use crate::{
+    app::app_main::App,
+    ast::{
+        expr_ast::walk_expr,
+        stmt_ast::{walk_stmt, BindingPattern, ListPattern, Stmt},
+    },
+    interpreter::{environment::SplaxDeclarations, interpreter_main::Interpreter},
+    token::token_main::{Token, TokenLiterals},
+};
+use std::collections::HashMap;
+
+impl TypeChecker {
+    /// Constructor for TypeChecker.
+    pub fn new() -> Self {
+        spdlog::debug!("constructing new type checker.");
+        let mut checker = Self {
+            scopes: vec![HashMap::new()],
+            function_scope: 0,
+            function_return: None,
+            enums: HashMap::new(),
+            has_error: false,
+        };
+        checker.define_natives();
+        checker
+    }
+
+    /// Defines the signatures of the native functions the interpreter
+    /// registers, their parameters accept any type except the template of `format`.
+    fn define_natives(&mut self) {
+        let globals = Interpreter::get_globals();
+        for declaration in globals.values.values() {
+            let SplaxDeclarations::Natives(native) = declaration else {
+                continue;
+            };
+
+            let mut params = vec![SplaxType::Any; native.arity];
+            if native.name == "format" {
+                params[0] = SplaxType::String;
+            }
+            let function = FunctionType {
+                name: native.name.to_owned(),
+                params,
+                rest: native.rest,
+                returns: TypeChecker::native_returns(&native.name),
+            };
+            self.define(
+                native.name.to_owned(),
+                Binding {
+                    declared: None,
+                    inferred: SplaxType::Function(Box::new(function)),
+                },
+            );
+        }
+    }
+
+    /// Returns the type of the values a native function returns,
+    /// `any` for natives whose result depends on their arguments.
+    /// # Arguments
+    /// * `name` - name of the native function.
+    fn native_returns(name: &str) -> SplaxType {
+        match name {
+            "clock" | "len" => SplaxType::Number,
+            "str" | "type" | "format" | "read_file" | "path_join" => SplaxType::String,
+            "exists" => SplaxType::Boolean,
+            "read_lines" | "list_dir" => SplaxType::List,
+            "write_file" | "append_file" | "remove" | "mkdir" | "set_env" | "exit" => {
+                SplaxType::Null
+            }
+            _ => SplaxType::Any,
+        }
+    }
+
+    /// top level public method to check a program.
+    /// returns true if no type errors were found.
+    /// # Arguments
+    /// * `statements` - A vector of statements aka a program.
+    pub fn check(&mut self, statements: &Vec<Stmt>) -> bool {
+        spdlog::info!("start type checking");
+        for statement in statements {
+            walk_stmt(self, statement);
+        }
+
+        !self.has_error
+    }
+
+    /// Reports a type error.
+    /// # Arguments
+    /// * `line` - line number where the error was found.
+    /// * `message` - message for the error.
+    pub fn report(&mut self, line: usize, message: String) {
+        self.has_error = true;
+        App::error(line, message);
+    }
+
+    /// Defines a variable in the innermost scope.
+    /// # Arguments
+    /// * `name` - name of the variable.
+    /// * `binding` - the variable's types.
+    pub fn define(&mut self, name: String, binding: Binding) {
+        spdlog::trace!("checker defining {name} as {binding:?}");
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, binding);
+        }
+    }
+
+    /// Finds a variable, returns its binding and if it belongs to an
+    /// enclosing function's scope.
+    /// # Arguments
+    /// * `name` - name of the variable.
+    pub fn lookup(&mut self, name: &str) -> Option<(&mut Binding, bool)> {
+        let function_scope = self.function_scope;
+        for (depth, scope) in self.scopes.iter_mut().enumerate().rev() {
+            if let Some(binding) = scope.get_mut(name) {
+                return Some((binding, depth < function_scope));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the type of a variable when it is read.
+    /// Unannotated variables from outside the current function can be
+    /// reassigned before the function is called, so they are `any`,
+    /// except functions which keep their signature.
+    /// # Arguments
+    /// * `name` - name of the variable.
+    pub fn type_of(&mut self, name: &str) -> SplaxType {
+        match self.lookup(name) {
+            Some((binding, outside_function)) => match &binding.declared {
+                Some(declared) => declared.clone(),
+                None if outside_function && !matches!(binding.inferred, SplaxType::Function(_)) => {
+                    SplaxType::Any
+                }
+                None => binding.inferred.clone(),
+            },
+            None => SplaxType::Any,
+        }
+    }
+
+    /// Resolves a type annotation into a type.
+    /// # Arguments
+    /// * `annotation` - name token of the type.
+    pub fn resolve_type(&mut self, annotation: &Token) -> SplaxType {
+        match annotation.lexeme.as_str() {
+            "any" => SplaxType::Any,
+            "number" => SplaxType::Number,
+            "string" => SplaxType::String,
+            "boolean" => SplaxType::Boolean,
+            "null" => SplaxType::Null,
+            "list" => SplaxType::List,
+            "fn" => SplaxType::Any,
+            name if self.enums.contains_key(name) => SplaxType::Enum(name.to_string()),
+            name => {
+                self.report(annotation.line, format!("Unknown type '{}'.", name));
+                SplaxType::Any
+            }
+        }
+    }
+
+    /// Resolves an optional type annotation, unannotated is `any`.
+    /// # Arguments
+    /// * `annotation` - optional name token of the type.
+    pub fn resolve_optional_type(&mut self, annotation: &Option<Token>) -> SplaxType {
+        match annotation {
+            Some(annotation) => self.resolve_type(annotation),
+            None => SplaxType::Any,
+        }
+    }
+
+    /// Returns the type of a literal value.
+    /// # Arguments
+    /// * `literal` - the literal.
+    pub fn literal_type(literal: &TokenLiterals) -> SplaxType {
+        match literal {
+            TokenLiterals::Number(_) => SplaxType::Number,
+            TokenLiterals::String(_) => SplaxType::String,
+            TokenLiterals::Boolean(_) => SplaxType::Boolean,
+            TokenLiterals::Null => SplaxType::Null,
+            TokenLiterals::List(_) => SplaxType::List,
+            // signatures of function values aren't known.
+            TokenLiterals::Function(_)
+            | TokenLiterals::Native(_)
+            | TokenLiterals::Module(_)
+            | TokenLiterals::Pattern(_) => SplaxType::Any,
+            TokenLiterals::Enum(value) => SplaxType::Enum(value.enum_name.to_owned()),
+        }
+    }
+
+    /// Checks that a destructured value can be a list, and checks the
+    /// defaults of the pattern. Elements of lists aren't typed, so the
+    /// names of the pattern are `any`.
+    /// # Arguments
+    /// * `pattern` - the list pattern.
+    /// * `value` - type of the value being destructured.
+    pub fn check_list_pattern(&mut self, pattern: &ListPattern, value: &SplaxType) {
+        if !SplaxType::List.accepts(value) {
+            self.report(
+                pattern.bracket.line,
+                format!(
+                    "Can't destructure a '{}' with the pattern {}, it isn't a list.",
+                    value, pattern
+                ),
+            );
+        }
+
+        for element in &pattern.elements {
+            if let Some(default) = &element.default {
+                walk_expr(self, default);
+            }
+            if let BindingPattern::List(nested) = &element.pattern {
+                self.check_list_pattern(nested, &SplaxType::Any);
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::app::app_main::App;
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -22,6 +23,26 @@ pub enum Commands {
     Run {
         #[arg(required = true)]
         filepath: PathBuf,
+
+        // keeps every call on the stack, for debugging.
+        #[arg(long, help = "Disable tail call optimisation")]
+        no_tco: bool,
+
+        // limit of nested function calls.
+        #[arg(long, default_value_t = App::DEFAULT_MAX_DEPTH, value_parser = max_depth, help = "Maximum depth of nested function calls")]
+        max_depth: usize,
+
+        // arguments for the script, after `--`.
+        #[arg(last = true, help = "Arguments passed to the script as 'args'")]
+        args: Vec<String>,
+    },
+
+    // file path to type check
+    #[command()]
+    #[command(about = "Type check a file without running it")]
+    Check {
+        #[arg(required = true)]
+        filepath: PathBuf,
     },
 
     // see docs for a query.
@@ -29,3 +50,18 @@ pub enum Commands {
     #[command(about = "See docs")]
     Docs,
 }
+
+/// Parses the limit of nested function calls, deeper limits
+/// than the stack can hold would crash instead of raising an error.
+/// # Arguments
+/// * `value` - the value given on the command line.
+fn max_depth(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(depth) if depth <= App::MAX_DEPTH_LIMIT => Ok(depth),
+        Ok(_) => Err(format!(
+            "the stack only has room for calls nested {} deep",
+            App::MAX_DEPTH_LIMIT
+        )),
+        Err(error) => Err(error.to_string()),
+    }
+}
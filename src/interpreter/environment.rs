@@ -1,26 +1,46 @@
+use super::natives::NativeFunction;
 use crate::{
     app::app_main::App,
-    ast::expr_ast::FunctionObject,
+    ast::{expr_ast::FunctionObject, stmt_ast::StmtEnum},
     token::token_main::{Token, TokenLiterals},
 };
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 #[derive(Debug, Clone)]
 pub enum SplaxDeclarations {
     Literals(Box<TokenLiterals>),
     Functions(Box<FunctionObject>),
+    Natives(Box<NativeFunction>),
+    Enums(Box<StmtEnum>),
 }
 
+/// Environments are shared between every block, function and closure
+/// which can reach them, so assignments are seen by all of them.
+pub type SharedEnvironment = Rc<RefCell<Environment>>;
+
 // Top level 'Environment' Data structure to store state of the interpreter.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Environment {
-    pub enclosing: Option<Box<Environment>>,
+    pub enclosing: Option<SharedEnvironment>,
     pub values: HashMap<String, SplaxDeclarations>,
 }
 
+/// Only the names are shown, environments hold the functions which
+/// capture them, so showing values would never end.
+impl std::fmt::Debug for Environment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<&String> = self.values.keys().collect();
+        names.sort();
+        f.debug_struct("Environment")
+            .field("names", &names)
+            .field("enclosing", &self.enclosing.is_some())
+            .finish()
+    }
+}
+
 impl Environment {
     // constructor.
-    pub fn new(enclosing: Option<Box<Environment>>) -> Self {
+    pub fn new(enclosing: Option<SharedEnvironment>) -> Self {
         spdlog::debug!("creating new environment hashmap.");
         Self {
             enclosing,
@@ -28,6 +48,13 @@ impl Environment {
         }
     }
 
+    /// Creates a new shared environment enclosed by another one.
+    /// # Arguments
+    /// * `enclosing` - The enclosing environment.
+    pub fn new_shared(enclosing: &SharedEnvironment) -> SharedEnvironment {
+        Rc::new(RefCell::new(Environment::new(Some(Rc::clone(enclosing)))))
+    }
+
     /// Defines new variable in environment.
     /// # Arguments
     /// * `name` - String name of the variable.
@@ -77,9 +104,9 @@ impl Environment {
         }
 
         // recursively find in enclosing environments.
-        if let Some(enclosing) = &mut self.enclosing {
+        if let Some(enclosing) = &self.enclosing {
             spdlog::trace!("trying to assign in enclosing environment");
-            return enclosing.assign_from_str(name, value);
+            return enclosing.borrow_mut().assign_from_str(name, value);
         }
 
         None
@@ -113,7 +140,7 @@ impl Environment {
         // searching the indentifier in enclosing environment.
         if let Some(enclosing) = &self.enclosing {
             spdlog::trace!("trying to find '{name}' in enclosing environment");
-            return enclosing.get_from_str(name);
+            return enclosing.borrow().get_from_str(name);
         }
 
         None
@@ -0,0 +1,21 @@
+/// Error raised by `App::runtime_error`, it unwinds the interpreter
+/// until a `try` statement catches it, or stops the program.
+#[derive(Debug, Clone)]
+pub struct RuntimeError {
+    // line number where error occured.
+    pub line: usize,
+    // error message
+    pub message: String,
+}
+
+impl std::fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[line {}] : {}", self.line, self.message)
+    }
+}
+
+impl RuntimeError {
+    pub fn new(line: usize, message: String) -> Self {
+        Self { line, message }
+    }
+}
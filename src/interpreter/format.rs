@@ -0,0 +1,339 @@
+use super::interpreter_main::Interpreter;
+use crate::{app::app_main::App, token::token_main::TokenLiterals};
+use std::{iter::Peekable, str::Chars};
+
+/// How a value is written by a `{:spec}` placeholder,
+/// `[[fill]align][+][0][width][.precision][type]`.
+#[derive(Debug, Default)]
+struct FormatSpec {
+    // character used for padding, a space by default.
+    fill: Option<char>,
+    // `<`, `>` or `^`, numbers are aligned right and other values left by default.
+    align: Option<char>,
+    // if positive numbers get a `+`.
+    sign: bool,
+    // if numbers are padded with zeros after their sign.
+    zero: bool,
+    // least number of characters to write.
+    width: usize,
+    // digits after the point for numbers, most characters for other values.
+    precision: Option<usize>,
+    // `x`, `X`, `o` or `b` to write whole numbers in another base.
+    radix: Option<char>,
+}
+
+impl FormatSpec {
+    /// Parses the part of a placeholder after the `:`.
+    /// # Arguments
+    /// * `spec` - the spec.
+    fn parse(spec: &str) -> Result<Self, String> {
+        let chars: Vec<char> = spec.chars().collect();
+        let mut format = FormatSpec::default();
+        let mut current = 0;
+
+        // alignment, with an optional fill character before it.
+        if chars.len() > 1 && matches!(chars[1], '<' | '>' | '^') {
+            format.fill = Some(chars[0]);
+            format.align = Some(chars[1]);
+            current = 2;
+        } else if !chars.is_empty() && matches!(chars[0], '<' | '>' | '^') {
+            format.align = Some(chars[0]);
+            current = 1;
+        }
+
+        if chars.get(current) == Some(&'+') {
+            format.sign = true;
+            current += 1;
+        }
+
+        if chars.get(current) == Some(&'0') {
+            format.zero = true;
+            current += 1;
+        }
+
+        let start = current;
+        while chars
+            .get(current)
+            .is_some_and(|character| character.is_ascii_digit())
+        {
+            current += 1;
+        }
+        if current > start {
+            format.width = FormatSpec::number(&chars[start..current], spec)?;
+        }
+
+        if chars.get(current) == Some(&'.') {
+            current += 1;
+            let start = current;
+            while chars
+                .get(current)
+                .is_some_and(|character| character.is_ascii_digit())
+            {
+                current += 1;
+            }
+            if current == start {
+                return Err(format!("Expected digits after '.' in '{{:{}}}'.", spec));
+            }
+            format.precision = Some(FormatSpec::number(&chars[start..current], spec)?);
+        }
+
+        if let Some(radix) = chars
+            .get(current)
+            .filter(|radix| matches!(radix, 'x' | 'X' | 'o' | 'b'))
+        {
+            format.radix = Some(*radix);
+            current += 1;
+        }
+
+        if current < chars.len() {
+            return Err(format!(
+                "Invalid format spec '{{:{}}}', expected '[[fill]align][+][0][width][.precision][x|X|o|b]'.",
+                spec
+            ));
+        }
+
+        Ok(format)
+    }
+
+    /// Parses the digits of a width or precision,
+    /// which can't be more than the longest string.
+    /// # Arguments
+    /// * `digits` - the digits.
+    /// * `spec` - the whole spec, for errors.
+    fn number(digits: &[char], spec: &str) -> Result<usize, String> {
+        match digits.iter().collect::<String>().parse() {
+            Ok(number) if number <= App::MAX_STRING_LENGTH => Ok(number),
+            _ => Err(format!(
+                "Number too large in '{{:{}}}', it can be at most {}.",
+                spec,
+                App::MAX_STRING_LENGTH
+            )),
+        }
+    }
+
+    /// Writes a value following the spec.
+    /// # Arguments
+    /// * `value` - the value.
+    fn apply(&self, value: &TokenLiterals) -> Result<String, String> {
+        let (sign, body) = match value {
+            TokenLiterals::Number(number) => self.number_parts(*number)?,
+            value if self.radix.is_some() => {
+                return Err(format!(
+                    "Can only write numbers in another base, got a '{}'.",
+                    value.type_name()
+                ));
+            }
+            value => {
+                let text = value.to_string();
+                match self.precision {
+                    Some(precision) => (String::new(), text.chars().take(precision).collect()),
+                    None => (String::new(), text),
+                }
+            }
+        };
+
+        let length = sign.chars().count() + body.chars().count();
+        let padding = self.width.saturating_sub(length);
+        let is_number = matches!(value, TokenLiterals::Number(_));
+
+        // fill characters can take up to 4 bytes each.
+        let fill_length = if self.zero && is_number {
+            1
+        } else {
+            self.fill.map_or(1, char::len_utf8)
+        };
+        if padding * fill_length + sign.len() + body.len() > App::MAX_STRING_LENGTH {
+            return Err(format!(
+                "Formatted value would be longer than {} bytes.",
+                App::MAX_STRING_LENGTH
+            ));
+        }
+
+        // zeros go between the sign and the digits.
+        if self.zero && is_number {
+            return Ok(format!("{}{}{}", sign, "0".repeat(padding), body));
+        }
+
+        let fill = self.fill.unwrap_or(' ').to_string();
+        let align = self.align.unwrap_or(if is_number { '>' } else { '<' });
+        let (left, right) = match align {
+            '<' => (0, padding),
+            '^' => (padding / 2, padding - padding / 2),
+            _ => (padding, 0),
+        };
+        Ok(format!(
+            "{}{}{}{}",
+            fill.repeat(left),
+            sign,
+            body,
+            fill.repeat(right)
+        ))
+    }
+
+    /// Splits a number into its sign and digits.
+    /// # Arguments
+    /// * `number` - the number.
+    fn number_parts(&self, number: f64) -> Result<(String, String), String> {
+        let sign = if number.is_sign_negative() && number != 0_f64 {
+            "-"
+        } else if self.sign {
+            "+"
+        } else {
+            ""
+        };
+
+        let magnitude = number.abs();
+        let body = match (self.radix, self.precision) {
+            (Some(radix), _) => {
+                if magnitude.fract() != 0_f64 || magnitude >= u64::MAX as f64 {
+                    return Err(format!(
+                        "Can only write whole numbers in another base, got {}.",
+                        number
+                    ));
+                }
+                let magnitude = magnitude as u64;
+                match radix {
+                    'x' => format!("{:x}", magnitude),
+                    'X' => format!("{:X}", magnitude),
+                    'o' => format!("{:o}", magnitude),
+                    _ => format!("{:b}", magnitude),
+                }
+            }
+            (None, Some(precision)) => {
+                // numbers never have more than 1074 digits after the point,
+                // and rust can't format with a precision above u16::MAX.
+                let digits = precision.min(1074);
+                let mut body = format!("{:.*}", digits, magnitude);
+                body.push_str(&"0".repeat(precision - digits));
+                body
+            }
+            (None, None) => magnitude.to_string(),
+        };
+
+        Ok((sign.to_string(), body))
+    }
+}
+
+impl Interpreter {
+    /// `format(template, ...values)`, fills the placeholders of the template.
+    /// `{}` takes the next value and `{1}` the value at a position.
+    /// `{{` and `}}` write braces.
+    pub fn native_format(
+        _: &mut Interpreter,
+        mut arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let template = Interpreter::expect_string("format", "template", &arguments[0], line);
+        let values = arguments.split_off(1);
+
+        match Interpreter::format_template(&template, &values) {
+            Ok(text) => TokenLiterals::String(text),
+            Err(message) => {
+                App::runtime_error(line, message);
+                panic!()
+            }
+        }
+    }
+
+    /// Fills the placeholders of a format template.
+    /// # Arguments
+    /// * `template` - the format template.
+    /// * `values` - values for the positional placeholders.
+    fn format_template(template: &str, values: &[TokenLiterals]) -> Result<String, String> {
+        let mut text = String::new();
+        let mut used = vec![false; values.len()];
+        let mut next = 0;
+        let mut chars = template.chars().peekable();
+
+        while let Some(character) = chars.next() {
+            match character {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '}' => {
+                    return Err(
+                        "Unmatched '}' in format template, use '}}' to write a '}'.".to_string()
+                    )
+                }
+                '{' => {
+                    let placeholder = Interpreter::read_placeholder(&mut chars)?;
+                    let (argument, spec) = match placeholder.split_once(':') {
+                        Some((argument, spec)) => (argument, spec),
+                        None => (placeholder.as_str(), ""),
+                    };
+                    let spec = FormatSpec::parse(spec)?;
+
+                    let value = if argument.is_empty() {
+                        next += 1;
+                        Interpreter::positional(values, &mut used, next - 1)?
+                    } else if let Ok(index) = argument.parse::<usize>() {
+                        Interpreter::positional(values, &mut used, index)?
+                    } else {
+                        return Err(format!(
+                            "Named placeholders like '{{{}}}' aren't supported until splax has maps, pass the value by position instead.",
+                            argument
+                        ));
+                    };
+
+                    text.push_str(&spec.apply(&value)?);
+                }
+                character => text.push(character),
+            }
+        }
+
+        let used_count = used.iter().filter(|used| **used).count();
+        if used_count < values.len() {
+            return Err(format!(
+                "format got {} values but the template only uses {}.",
+                values.len(),
+                used_count
+            ));
+        }
+
+        Ok(text)
+    }
+
+    /// Reads a placeholder up to its closing `}`, after the opening `{`.
+    /// # Arguments
+    /// * `chars` - characters of the template.
+    fn read_placeholder(chars: &mut Peekable<Chars>) -> Result<String, String> {
+        let mut placeholder = String::new();
+        for character in chars.by_ref() {
+            match character {
+                '}' => return Ok(placeholder),
+                '{' => break,
+                character => placeholder.push(character),
+            }
+        }
+
+        Err("Unclosed '{' in format template, use '{{' to write a '{'.".to_string())
+    }
+
+    /// Returns the value for a positional placeholder.
+    /// # Arguments
+    /// * `values` - values given to format.
+    /// * `used` - which values were used so far.
+    /// * `index` - position of the value.
+    fn positional(
+        values: &[TokenLiterals],
+        used: &mut [bool],
+        index: usize,
+    ) -> Result<TokenLiterals, String> {
+        match values.get(index) {
+            Some(value) => {
+                used[index] = true;
+                Ok(value.clone())
+            }
+            None => Err(format!(
+                "format template uses value {} but only {} values were given.",
+                index + 1,
+                values.len()
+            )),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use super::{environment::Environment, interpreter_main::Interpreter};
+use crate::{app::app_main::App, token::token_main::TokenLiterals};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+impl Interpreter {
+    /// Defines the file system native functions.
+    /// # Arguments
+    /// * `globals` - the global environment.
+    pub fn define_fs(globals: &mut Environment) {
+        Interpreter::define_native(globals, "read_file", 1, Interpreter::fs_read_file);
+        Interpreter::define_native(globals, "write_file", 2, Interpreter::fs_write_file);
+        Interpreter::define_native(globals, "append_file", 2, Interpreter::fs_append_file);
+        Interpreter::define_native(globals, "read_lines", 1, Interpreter::fs_read_lines);
+        Interpreter::define_native(globals, "exists", 1, Interpreter::fs_exists);
+        Interpreter::define_native(globals, "remove", 1, Interpreter::fs_remove);
+        Interpreter::define_native(globals, "mkdir", 1, Interpreter::fs_mkdir);
+        Interpreter::define_native(globals, "list_dir", 1, Interpreter::fs_list_dir);
+        Interpreter::define_native(globals, "path_join", 2, Interpreter::fs_path_join);
+    }
+
+    /// Resolves the path argument of a file system function,
+    /// relative paths are relative to the script's directory.
+    /// # Arguments
+    /// * `function` - name of the function.
+    /// * `value` - the path argument.
+    /// * `line` - line of the call.
+    fn resolve_path(&self, function: &str, value: &TokenLiterals, line: usize) -> PathBuf {
+        let path = Interpreter::expect_string(function, "path", value, line);
+        let path = Path::new(&path);
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.script_dir.join(path)
+        }
+    }
+
+    /// Reports a failed file system operation.
+    /// # Arguments
+    /// * `line` - line of the call.
+    /// * `action` - what was being done, like "read file".
+    /// * `path` - the path it was done on.
+    /// * `error` - error from the operating system.
+    fn fs_error(line: usize, action: &str, path: &Path, error: io::Error) -> TokenLiterals {
+        App::runtime_error(
+            line,
+            format!("Can't {} '{}': {}.", action, path.display(), error),
+        );
+        panic!()
+    }
+
+    /// `read_file(path)`, contents of a file as a string.
+    fn fs_read_file(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("read_file", &arguments[0], line);
+        match fs::read_to_string(&path) {
+            Ok(contents) => TokenLiterals::String(contents),
+            Err(error) => Interpreter::fs_error(line, "read file", &path, error),
+        }
+    }
+
+    /// `write_file(path, s)`, replaces the contents of a file, creating it if needed.
+    fn fs_write_file(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("write_file", &arguments[0], line);
+        let contents = Interpreter::expect_string("write_file", "s", &arguments[1], line);
+        match fs::write(&path, contents) {
+            Ok(_) => TokenLiterals::Null,
+            Err(error) => Interpreter::fs_error(line, "write file", &path, error),
+        }
+    }
+
+    /// `append_file(path, s)`, adds to the end of a file, creating it if needed.
+    fn fs_append_file(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("append_file", &arguments[0], line);
+        let contents = Interpreter::expect_string("append_file", "s", &arguments[1], line);
+        let appended = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()));
+        match appended {
+            Ok(_) => TokenLiterals::Null,
+            Err(error) => Interpreter::fs_error(line, "append to file", &path, error),
+        }
+    }
+
+    /// `read_lines(path)`, lines of a file as a list of strings, without line endings.
+    fn fs_read_lines(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("read_lines", &arguments[0], line);
+        match fs::read_to_string(&path) {
+            Ok(contents) => TokenLiterals::new_list(
+                contents
+                    .lines()
+                    .map(|line| TokenLiterals::String(line.to_string()))
+                    .collect(),
+            ),
+            Err(error) => Interpreter::fs_error(line, "read file", &path, error),
+        }
+    }
+
+    /// `exists(path)`, if a file or directory exists.
+    fn fs_exists(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("exists", &arguments[0], line);
+        TokenLiterals::Boolean(path.exists())
+    }
+
+    /// `remove(path)`, deletes a file or an empty directory.
+    fn fs_remove(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("remove", &arguments[0], line);
+        let removed = if path.is_dir() {
+            fs::remove_dir(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        match removed {
+            Ok(_) => TokenLiterals::Null,
+            Err(error) => Interpreter::fs_error(line, "remove", &path, error),
+        }
+    }
+
+    /// `mkdir(path)`, creates a directory and any missing parent directories.
+    fn fs_mkdir(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("mkdir", &arguments[0], line);
+        match fs::create_dir_all(&path) {
+            Ok(_) => TokenLiterals::Null,
+            Err(error) => Interpreter::fs_error(line, "create directory", &path, error),
+        }
+    }
+
+    /// `list_dir(path)`, sorted names of the entries of a directory.
+    fn fs_list_dir(
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let path = interpreter.resolve_path("list_dir", &arguments[0], line);
+        let entries = fs::read_dir(&path).and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+                .collect::<Result<Vec<String>, io::Error>>()
+        });
+
+        match entries {
+            Ok(mut names) => {
+                names.sort();
+                TokenLiterals::new_list(names.into_iter().map(TokenLiterals::String).collect())
+            }
+            Err(error) => Interpreter::fs_error(line, "list directory", &path, error),
+        }
+    }
+
+    /// `path_join(a, b)`, joins two paths with the platform's separator.
+    /// If b is absolute it replaces a.
+    fn fs_path_join(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let base = Interpreter::expect_string("path_join", "a", &arguments[0], line);
+        let path = Interpreter::expect_string("path_join", "b", &arguments[1], line);
+        TokenLiterals::String(Path::new(&base).join(path).to_string_lossy().to_string())
+    }
+}
@@ -26,6 +26,9 @@ impl Interpreter {
             )),
         );
 
+        // builtin native functions.
+        Interpreter::define_builtins(&mut globals);
+
         globals
     }
 }
@@ -16,7 +16,7 @@ impl ExprVisitor<TokenLiterals> for Interpreter {
     /// * `expr` - Binary Expression.
     fn visit_assign_expr(&mut self, expr: &ast::expr_ast::ExprAssign) -> TokenLiterals {
         let value = walk_expr(self, &expr.value);
-        self.environment.assign(
+        self.environment.borrow_mut().assign(
             expr.name.to_owned(),
             super::environment::SplaxDeclarations::Literals(Box::new(value.to_owned())),
         );
@@ -32,120 +32,7 @@ impl ExprVisitor<TokenLiterals> for Interpreter {
         let right = walk_expr(self, &expr.right);
 
         spdlog::debug!("interpreting binary expression: {:?}", expr);
-
-        // matching left operand.
-        match left {
-            // if left operand is a number.
-            TokenLiterals::Number(left_value) => match right {
-                // when both left and right operands are numbers.
-                TokenLiterals::Number(right_value) => match operator.token_type {
-                    // Operators supported by two number operands.
-                    TokenType::Plus => TokenLiterals::Number(left_value + right_value),
-                    TokenType::Minus => TokenLiterals::Number(left_value - right_value),
-                    TokenType::Star => TokenLiterals::Number(left_value * right_value),
-                    TokenType::Slash => TokenLiterals::Number(left_value / right_value),
-                    TokenType::Mod => TokenLiterals::Number(left_value % right_value),
-
-                    // comparison operator.
-                    TokenType::Greater => TokenLiterals::Boolean(left_value > right_value),
-                    TokenType::GreaterEqual => TokenLiterals::Boolean(left_value >= right_value),
-                    TokenType::Less => TokenLiterals::Boolean(left_value < right_value),
-                    TokenType::LessEqual => TokenLiterals::Boolean(left_value <= right_value),
-
-                    // equality operators.
-                    TokenType::BangEqual => TokenLiterals::Boolean(left_value != right_value),
-                    TokenType::EqualEqual => TokenLiterals::Boolean(left_value == right_value),
-
-                    // any other operators are not for number.
-                    _ => {
-                        App::runtime_error(
-                            operator.line,
-                            "unsupported operator for 'Number'".to_string(),
-                        );
-                        panic!("This is unreachable, if somehow you managed to trigger this, idk.")
-                    }
-                }, // operator matching for both operands number types.
-
-                // any other type when the left operand is a number will result in a runtime errror.
-                _ => {
-                    App::runtime_error(
-                        operator.line,
-                        "unsupported operand type(s): 'Number' with a non 'Number'".to_string(),
-                    );
-                    panic!();
-                }
-            }, // left operand matching: Number,
-
-            // when left operand is a string.
-            TokenLiterals::String(left_value) => match right {
-                // when both left and right operands are strings.
-                TokenLiterals::String(right_value) => match operator.token_type {
-                    // arthematic operators for strings.
-                    TokenType::Plus => TokenLiterals::String(format!(
-                        "{}{}",
-                        &left_value[1..left_value.len() - 1],
-                        &right_value[1..right_value.len() - 1],
-                    )),
-
-                    // equality operators for strings.
-                    TokenType::BangEqual => TokenLiterals::Boolean(left_value != right_value),
-                    TokenType::EqualEqual => TokenLiterals::Boolean(left_value == right_value),
-
-                    // any other operators are not for strings.
-                    _ => {
-                        App::runtime_error(
-                            operator.line,
-                            "unsupported operator for 'String'".to_string(),
-                        );
-                        panic!()
-                    }
-                }, // operator matching for both operands string types.
-
-                // any other type when the left operand is a string will result in a runtime errror.
-                _ => {
-                    App::runtime_error(
-                        operator.line,
-                        "unsupported operand type(s): 'String' with a non 'String'".to_string(),
-                    );
-                    panic!()
-                }
-            },
-
-            TokenLiterals::Boolean(left_value) => match right {
-                TokenLiterals::Boolean(right_value) => match operator.token_type {
-                    // equality operators for booleans.
-                    TokenType::BangEqual => TokenLiterals::Boolean(left_value != right_value),
-                    TokenType::EqualEqual => TokenLiterals::Boolean(left_value == right_value),
-
-                    // any other operators are not for booleans.
-                    _ => {
-                        App::runtime_error(
-                            operator.line,
-                            "unsupported operator for 'Boolean'".to_string(),
-                        );
-                        panic!()
-                    }
-                }, // operator matching for both operands boolean types.
-
-                // any other type when the left operand is a boolean will result in a runtime errror.
-                _ => {
-                    App::runtime_error(
-                        operator.line,
-                        "unsupported operand type(s): 'Boolean' with a non 'Boolean'".to_string(),
-                    );
-                    panic!()
-                }
-            },
-
-            // when right literal is neither number nor string nor boolean.
-            _ => {
-                App::runtime_error(
-                    operator.line,
-                    "unsupported operation for this type".to_string(),
-                );
-                panic!()
-            }
-        }
+        Interpreter::binary_operation(operator, left, right)
     }
 
     /// Evalute group expressions.
@@ -186,10 +73,19 @@ impl ExprVisitor<TokenLiterals> for Interpreter {
     /// * `expr` - Variable expression.
     fn visit_let_expr(&mut self, expr: &ast::expr_ast::ExprVariable) -> TokenLiterals {
         spdlog::trace!("interpreting variable expression: {:?}", expr);
-        match self.environment.get(expr.name.to_owned()) {
+        match self.environment.borrow().get(expr.name.to_owned()) {
             SplaxDeclarations::Literals(literal) => *literal,
-            SplaxDeclarations::Functions(function) => {
-                TokenLiterals::String(format!("<fn '{}'>", function.declaration.name.lexeme))
+            SplaxDeclarations::Functions(function) => TokenLiterals::Function(function),
+            SplaxDeclarations::Natives(function) => TokenLiterals::Native(function),
+            SplaxDeclarations::Enums(declaration) => {
+                App::runtime_error(
+                    expr.name.line,
+                    format!(
+                        "Used the enum '{}' as a value, use one of its variants instead.",
+                        declaration.name.lexeme
+                    ),
+                );
+                panic!()
             }
         }
     }
@@ -200,7 +96,13 @@ impl ExprVisitor<TokenLiterals> for Interpreter {
     fn visit_logical_expr(&mut self, expr: &ast::expr_ast::ExprLogical) -> TokenLiterals {
         let left = walk_expr(self, &expr.left);
 
-        if let TokenType::Or = expr.operator.token_type {
+        if let TokenType::QuestionQuestion = expr.operator.token_type {
+            // in case of "??", the right operand is only needed when
+            // the left operand is null.
+            if !matches!(left, TokenLiterals::Null) {
+                return left;
+            }
+        } else if let TokenType::Or = expr.operator.token_type {
             // in case of "OR", we check if left operand is true,
             // if is is true, then we dont need to check the right operand as well
             // we can safely assume that the entire expression will be true.
@@ -222,22 +124,247 @@ impl ExprVisitor<TokenLiterals> for Interpreter {
 
     fn visit_call_expr(&mut self, expr: &ast::expr_ast::ExprCall) -> TokenLiterals {
         let callee = expr.callee.clone();
-        let mut arguments = vec![];
-        for arg in &expr.arguments {
-            arguments.push(walk_expr(self, arg))
+
+        if let Expr::Get(get) = &callee {
+            // calling enum variant constructors, `Enum.Variant(fields)`.
+            if let Some(declaration) = self.get_enum(&get.object) {
+                let arguments = self.evaluate_arguments(expr);
+                return Interpreter::construct_variant(&declaration, &get.name, arguments);
+            }
+
+            // calling methods and module functions, the value is evaluated before the arguments.
+            let object = walk_expr(self, &get.object);
+            if self.short_circuit {
+                return TokenLiterals::Null;
+            }
+            // optional method calls, `value?.name()`, short-circuit to null without evaluating arguments.
+            if get.optional && matches!(object, TokenLiterals::Null) {
+                spdlog::trace!("optional method call on null value, short-circuiting.");
+                self.short_circuit = true;
+                return TokenLiterals::Null;
+            }
+            let arguments = self.evaluate_arguments(expr);
+            return self.call_method(object, &get.name, arguments, expr.paren.line);
         }
 
         if let Expr::Variable(callee) = callee {
-            if let SplaxDeclarations::Functions(function_body) = self.environment.get(callee.name) {
-                function_body.call(self, arguments);
+            let declaration = self.environment.borrow().get(callee.name.to_owned());
+            // optional calls short-circuit to null, without evaluating arguments.
+            if expr.optional
+                && matches!(&declaration, SplaxDeclarations::Literals(literal) if matches!(**literal, TokenLiterals::Null))
+            {
+                spdlog::trace!("optional call on null callee, short-circuiting.");
+                self.short_circuit = true;
                 return TokenLiterals::Null;
-            } else {
-                App::runtime_error(expr.paren.line, "Called a non function type.".to_string());
-                panic!();
             }
+
+            let arguments = self.evaluate_arguments(expr);
+            match declaration {
+                SplaxDeclarations::Functions(function_body) => {
+                    return function_body.call(self, arguments, expr.paren.line);
+                }
+                SplaxDeclarations::Natives(function) => {
+                    return function.call(self, arguments, expr.paren.line);
+                }
+                SplaxDeclarations::Enums(declaration) => {
+                    App::runtime_error(
+                        expr.paren.line,
+                        format!(
+                            "Called an enum, use one of the variants of '{}' instead.",
+                            declaration.name.lexeme
+                        ),
+                    );
+                    panic!();
+                }
+                SplaxDeclarations::Literals(literal) => {
+                    // variables holding function values.
+                    if matches!(
+                        *literal,
+                        TokenLiterals::Function(_) | TokenLiterals::Native(_)
+                    ) {
+                        return self.call_value(*literal, arguments, expr.paren.line);
+                    }
+
+                    App::runtime_error(
+                        expr.paren.line,
+                        format!(
+                            "Called a non function type, '{}' is a '{}'.",
+                            callee.name.lexeme,
+                            literal.type_name()
+                        ),
+                    );
+                    panic!();
+                }
+            }
+        }
+
+        // any other expression, like `make()(1)`, has to evaluate to a function.
+        // the callee is evaluated once, before the arguments.
+        let callee = walk_expr(self, &callee);
+        if self.short_circuit {
+            return TokenLiterals::Null;
+        }
+        if expr.optional && matches!(callee, TokenLiterals::Null) {
+            spdlog::trace!("optional call on null callee, short-circuiting.");
+            self.short_circuit = true;
+            return TokenLiterals::Null;
+        }
+        let arguments = self.evaluate_arguments(expr);
+        self.call_value(callee, arguments, expr.paren.line)
+    }
+
+    /// Evalute destructuring assignments, the elements of the value are
+    /// assigned to the existing variables named in the pattern.
+    /// # Arguments
+    /// * `expr` - Destructure expression.
+    fn visit_destructure_expr(&mut self, expr: &ast::expr_ast::ExprDestructure) -> TokenLiterals {
+        spdlog::trace!("interpreting destructure expression: {:?}", expr);
+        let value = walk_expr(self, &expr.value);
+        self.bind_list(&expr.pattern, value.to_owned(), false, "");
+        value
+    }
+
+    /// Evalute conditional expressions.
+    /// Only the branch selected by the condition is evaluated.
+    /// # Arguments
+    /// * `expr` - Conditional expression.
+    fn visit_conditional_expr(&mut self, expr: &ast::expr_ast::ExprConditional) -> TokenLiterals {
+        spdlog::trace!("interpreting conditional expression: {:?}", expr);
+        if Interpreter::is_truth(walk_expr(self, &expr.condition)) {
+            walk_expr(self, &expr.then_branch)
+        } else {
+            walk_expr(self, &expr.else_branch)
         }
+    }
 
-        App::runtime_error(expr.paren.line, "Called a non identifier.".to_string());
-        panic!();
+    /// Evalute property access expressions.
+    /// Variants of enums and members of modules can be accessed,
+    /// fieldless variants evaluate to their value.
+    /// # Arguments
+    /// * `expr` - Get expression.
+    fn visit_get_expr(&mut self, expr: &ast::expr_ast::ExprGet) -> TokenLiterals {
+        spdlog::trace!("interpreting get expression: {:?}", expr);
+        if let Some(declaration) = self.get_enum(&expr.object) {
+            return Interpreter::construct_variant(&declaration, &expr.name, vec![]);
+        }
+
+        let object = walk_expr(self, &expr.object);
+        if self.short_circuit {
+            return TokenLiterals::Null;
+        }
+        // optional access, `object?.name`, is null when the object is null.
+        if expr.optional && matches!(object, TokenLiterals::Null) {
+            spdlog::trace!("optional property access on null value, short-circuiting.");
+            self.short_circuit = true;
+            return TokenLiterals::Null;
+        }
+        if let TokenLiterals::Module(module) = &object {
+            return Interpreter::get_member(module, &expr.name);
+        }
+
+        // methods can only be called, `value.name()`.
+        let message = match self.methods.get(object.type_name()) {
+            Some(methods) if methods.contains_key(&expr.name.lexeme) => format!(
+                "Method '{}' of '{}' has to be called, like '{}()'.",
+                expr.name.lexeme,
+                object.type_name(),
+                expr.name.lexeme
+            ),
+            _ => format!(
+                "Can't access property '{}' of a '{}', only enums and modules have properties.",
+                expr.name.lexeme,
+                object.type_name()
+            ),
+        };
+        App::runtime_error(expr.name.line, message);
+        panic!()
+    }
+
+    /// Evalute optional chains, `a?.b.c()`, the chain is null
+    /// if any `?.` in it short-circuited.
+    /// # Arguments
+    /// * `expr` - Optional chain expression.
+    fn visit_optional_chain_expr(
+        &mut self,
+        expr: &ast::expr_ast::ExprOptionalChain,
+    ) -> TokenLiterals {
+        spdlog::trace!("interpreting optional chain expression: {:?}", expr);
+        let value = walk_expr(self, &expr.expression);
+        // the short-circuit ends with the chain.
+        self.short_circuit = false;
+        value
+    }
+
+    /// Evalute list literals, spreads are expanded into the new list.
+    /// # Arguments
+    /// * `expr` - List expression.
+    fn visit_list_expr(&mut self, expr: &ast::expr_ast::ExprList) -> TokenLiterals {
+        spdlog::trace!("interpreting list expression: {:?}", expr);
+        TokenLiterals::new_list(self.evaluate_spread(&expr.elements))
+    }
+
+    /// Evalute index expressions, only lists and strings can be indexed with whole numbers.
+    /// Strings are indexed by characters, giving a string of one character.
+    /// # Arguments
+    /// * `expr` - Index expression.
+    fn visit_index_expr(&mut self, expr: &ast::expr_ast::ExprIndex) -> TokenLiterals {
+        spdlog::trace!("interpreting index expression: {:?}", expr);
+        let object = walk_expr(self, &expr.object);
+        if self.short_circuit {
+            return TokenLiterals::Null;
+        }
+        let index = walk_expr(self, &expr.index);
+
+        let (length, index) = match (&object, &index) {
+            (TokenLiterals::List(elements), TokenLiterals::Number(index)) => {
+                (elements.borrow().len(), *index)
+            }
+            (TokenLiterals::String(value), TokenLiterals::Number(index)) => {
+                (value.chars().count(), *index)
+            }
+            _ => {
+                App::runtime_error(
+                    expr.bracket.line,
+                    format!(
+                        "Can't index '{}' with '{}', only lists and strings can be indexed with numbers.",
+                        object.type_name(),
+                        index.type_name()
+                    ),
+                );
+                panic!()
+            }
+        };
+
+        if index.fract() != 0_f64 || index < 0_f64 || index as usize >= length {
+            App::runtime_error(
+                expr.bracket.line,
+                format!(
+                    "Index {} out of range for {} of length {}.",
+                    index,
+                    object.type_name().to_lowercase(),
+                    length
+                ),
+            );
+        }
+
+        match object {
+            TokenLiterals::List(elements) => elements.borrow()[index as usize].clone(),
+            TokenLiterals::String(value) => {
+                TokenLiterals::String(value.chars().skip(index as usize).take(1).collect())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Spreads are only valid inside list literals and call arguments,
+    /// which expand them before they reach here.
+    /// # Arguments
+    /// * `expr` - Spread expression.
+    fn visit_spread_expr(&mut self, expr: &ast::expr_ast::ExprSpread) -> TokenLiterals {
+        App::runtime_error(
+            expr.operator.line,
+            "Spread is only allowed in lists and call arguments.".to_string(),
+        );
+        panic!()
     }
 }
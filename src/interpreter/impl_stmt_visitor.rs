@@ -1,21 +1,28 @@
 use super::{
     environment::{Environment, SplaxDeclarations},
-    interpreter_main::Interpreter,
+    error::RuntimeError,
+    interpreter_main::{Interpreter, LoopSignal},
 };
-use crate::ast::{
-    self,
-    expr_ast::{walk_expr, FunctionObject},
-    stmt_ast::StmtVisitor,
+use crate::{
+    app::app_main::App,
+    ast::{
+        self,
+        expr_ast::{walk_expr, Expr, FunctionObject},
+        stmt_ast::{MatchPattern, StmtBlock, StmtVisitor},
+    },
+    token::token_main::TokenLiterals,
+};
+use std::{
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
 };
 
 /// Impl StmtVisitor pattern for Interpreter.
 impl StmtVisitor for Interpreter {
     // recursively executes a block of statements.
     fn visit_block_stmt(&mut self, stmt: &ast::stmt_ast::StmtBlock) {
-        self.execute_block(
-            stmt,
-            Box::new(Environment::new(Some(self.environment.clone()))),
-        );
+        self.execute_block(stmt, Environment::new_shared(&self.environment));
     }
 
     /// struct method walks/executes expression statements.
@@ -42,13 +49,10 @@ impl StmtVisitor for Interpreter {
         let value = walk_expr(self, &stmt.initialiser);
         spdlog::debug!(
             "defining variable : {} with value : {}",
-            stmt.name.lexeme,
+            stmt.pattern,
             value
         );
-        self.environment.define(
-            stmt.name.lexeme.to_owned(),
-            SplaxDeclarations::Literals(Box::new(value)),
-        );
+        self.bind_pattern(&stmt.pattern, value, true, "");
     }
 
     /// method walks/executes if statements.
@@ -77,19 +81,276 @@ impl StmtVisitor for Interpreter {
     /// # Arguments
     /// * `stmt` - stmtwhile stmt to walk.
     fn visit_while_stmt(&mut self, stmt: &ast::stmt_ast::StmtWhile) {
-        while Interpreter::is_truth(walk_expr(self, &stmt.condition)) {
+        // do while loops run their body once before checking the condition.
+        let mut skip_condition = stmt.do_while;
+        loop {
+            if !skip_condition && !Interpreter::is_truth(walk_expr(self, &stmt.condition)) {
+                break;
+            }
+            skip_condition = false;
+
             self.execute(&stmt.body);
+            if self.return_value.is_some() {
+                break;
+            }
+
+            // unlabelled signals target the innermost loop, labelled ones keep
+            // unwinding until they reach the loop with their label.
+            if let Some(signal) = self.loop_signal.take() {
+                let label = stmt.label.as_ref().map(|label| &label.lexeme);
+                match signal {
+                    LoopSignal::Break(target) if target.is_none() || target.as_ref() == label => {
+                        break
+                    }
+                    LoopSignal::Continue(target)
+                        if target.is_none() || target.as_ref() == label => {}
+                    signal => {
+                        self.loop_signal = Some(signal);
+                        break;
+                    }
+                }
+            }
+
+            if let Some(increment) = &stmt.increment {
+                walk_expr(self, increment);
+            }
+        }
+    }
+
+    /// method walks/executes defer statements, the statement is stored to
+    /// be executed when the enclosing block exits.
+    /// # Arguments
+    /// * `stmt` - stmtdefer stmt to walk.
+    fn visit_defer_stmt(&mut self, stmt: &ast::stmt_ast::StmtDefer) {
+        spdlog::debug!("deferring statement on line : {}", stmt.keyword.line);
+        if let Some(deferred) = self.deferred.last_mut() {
+            deferred.push(stmt.body.clone());
         }
     }
 
+    /// method walks/executes try statements. Runtime errors raised by the body
+    /// are caught, the interpreter is put back in the state it was in before
+    /// the body, and the handler runs with the error message bound to its name.
+    /// # Arguments
+    /// * `stmt` - stmttry stmt to walk.
+    fn visit_try_stmt(&mut self, stmt: &ast::stmt_ast::StmtTry) {
+        spdlog::debug!("executing try statement on line : {}", stmt.keyword.line);
+        let environment = Rc::clone(&self.environment);
+        let call_depth = self.call_stack.len();
+        let deferred = self.deferred.len();
+        let frame_base = self.frame_base;
+        let try_depth = self.try_depth;
+
+        self.try_depth += 1;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            self.execute_block(&stmt.body, Environment::new_shared(&self.environment));
+        }));
+        self.try_depth = try_depth;
+        let Err(error) = result else {
+            return;
+        };
+
+        // anything other than a runtime error is a bug in the interpreter itself.
+        let error = match error.downcast::<RuntimeError>() {
+            Ok(error) => error,
+            Err(error) => panic::resume_unwind(error),
+        };
+        spdlog::debug!(
+            "caught runtime error from line {} : {}",
+            error.line,
+            error.message
+        );
+
+        // the error may have come from deep inside function calls.
+        self.environment = environment;
+        self.call_stack.truncate(call_depth);
+        self.deferred.truncate(deferred);
+        self.frame_base = frame_base;
+        self.return_value = None;
+        self.loop_signal = None;
+        self.short_circuit = false;
+        self.tail_call = None;
+
+        let mut handler_environment = Environment::new(Some(Rc::clone(&self.environment)));
+        handler_environment.define(
+            stmt.name.lexeme.to_owned(),
+            SplaxDeclarations::Literals(Box::new(TokenLiterals::String(error.message))),
+        );
+        self.execute_block(&stmt.handler, Rc::new(RefCell::new(handler_environment)));
+    }
+
+    /// method walks/executes break statements.
+    /// # Arguments
+    /// * `stmt` - stmtbreak stmt to walk.
+    fn visit_break_stmt(&mut self, stmt: &ast::stmt_ast::StmtBreak) {
+        spdlog::debug!("break on line : {}", stmt.keyword.line);
+        let label = stmt.label.as_ref().map(|label| label.lexeme.to_owned());
+        self.loop_signal = Some(LoopSignal::Break(label));
+    }
+
+    /// method walks/executes continue statements.
+    /// # Arguments
+    /// * `stmt` - stmtcontinue stmt to walk.
+    fn visit_continue_stmt(&mut self, stmt: &ast::stmt_ast::StmtContinue) {
+        spdlog::debug!("continue on line : {}", stmt.keyword.line);
+        let label = stmt.label.as_ref().map(|label| label.lexeme.to_owned());
+        self.loop_signal = Some(LoopSignal::Continue(label));
+    }
+
+    /// method walks/executes function declarations, applying their decorators.
+    /// # Arguments
+    /// * `stmt` - stmtfunc stmt to walk.
     fn visit_function_stmt(&mut self, stmt: &ast::stmt_ast::StmtFunc) {
+        // functions keep the scope they are defined in, which they share with it.
         let function = FunctionObject {
-            declaration: stmt.clone(),
+            declaration: Rc::new(stmt.clone()),
+            closure: Rc::clone(&self.environment),
         };
 
-        self.environment.define(
+        // decorators are applied bottom up, each one is called with the
+        // value returned by the one below it.
+        let mut value = TokenLiterals::Function(Box::new(function));
+        for decorator in stmt.decorators.iter().rev() {
+            let decorator = walk_expr(self, decorator);
+            value = self.call_value(decorator, vec![value], stmt.name.line);
+        }
+
+        let declaration = match value {
+            TokenLiterals::Function(function) => SplaxDeclarations::Functions(function),
+            TokenLiterals::Native(function) => SplaxDeclarations::Natives(function),
+            value => SplaxDeclarations::Literals(Box::new(value)),
+        };
+        self.environment
+            .borrow_mut()
+            .define(stmt.name.lexeme.clone(), declaration);
+    }
+
+    /// method walks/executes return statements.
+    /// # Arguments
+    /// * `stmt` - stmtreturn stmt to walk.
+    fn visit_return_stmt(&mut self, stmt: &ast::stmt_ast::StmtReturn) {
+        let value = self.evaluate_tail(&stmt.value);
+        spdlog::debug!(
+            "returning value : {value} from line : {}",
+            stmt.keyword.line
+        );
+        self.return_value = Some(value);
+    }
+
+    /// method walks/executes enum declarations.
+    /// # Arguments
+    /// * `stmt` - stmtenum stmt to walk.
+    fn visit_enum_stmt(&mut self, stmt: &ast::stmt_ast::StmtEnum) {
+        spdlog::debug!("defining enum : {}", stmt.name.lexeme);
+        self.environment.borrow_mut().define(
             stmt.name.lexeme.clone(),
-            SplaxDeclarations::Functions(Box::new(function)),
+            SplaxDeclarations::Enums(Box::new(stmt.clone())),
+        );
+    }
+
+    /// method walks/executes match statements.
+    /// Executes the body of the first arm whose pattern matches,
+    /// with the pattern's bindings defined in a new environment.
+    /// # Arguments
+    /// * `stmt` - stmtmatch stmt to walk.
+    fn visit_match_stmt(&mut self, stmt: &ast::stmt_ast::StmtMatch) {
+        let subject = walk_expr(self, &stmt.subject);
+        spdlog::debug!(
+            "executing match on line {} with subject : {subject}",
+            stmt.keyword.line
+        );
+
+        for arm in &stmt.arms {
+            let mut environment = Environment::new(Some(Rc::clone(&self.environment)));
+
+            if let MatchPattern::Variant {
+                enum_name,
+                variant,
+                bindings,
+            } = &arm.pattern
+            {
+                // skip arms of other enums and variants.
+                let TokenLiterals::Enum(value) = &subject else {
+                    continue;
+                };
+                if value.enum_name != enum_name.lexeme || value.variant != variant.lexeme {
+                    continue;
+                }
+
+                if bindings.len() != value.fields.len() {
+                    App::runtime_error(
+                        variant.line,
+                        format!(
+                            "Pattern '{}.{}' binds {} fields, but the variant has {}.",
+                            enum_name.lexeme,
+                            variant.lexeme,
+                            bindings.len(),
+                            value.fields.len()
+                        ),
+                    );
+                }
+
+                // bind variant fields to the pattern's names.
+                for (binding, field) in bindings.iter().zip(value.fields.iter()) {
+                    environment.define(
+                        binding.lexeme.to_owned(),
+                        SplaxDeclarations::Literals(Box::new(field.clone())),
+                    );
+                }
+            }
+
+            spdlog::trace!("match arm matched, executing its body.");
+            self.execute_block(
+                &StmtBlock {
+                    block_statements: vec![arm.body.clone()],
+                },
+                Rc::new(RefCell::new(environment)),
+            );
+            return;
+        }
+
+        // matches have to handle every value they are given.
+        let unmatched = match &subject {
+            TokenLiterals::Enum(value) => {
+                format!("variant '{}.{}'", value.enum_name, value.variant)
+            }
+            _ => format!("value '{subject}'"),
+        };
+        App::runtime_error(
+            stmt.keyword.line,
+            format!("No arm of the match matches the {unmatched}."),
         );
     }
+
+    /// method walks/executes assert statements, failing assertions are runtime errors.
+    /// Operands of binary conditions are evaluated only once, so their values
+    /// can be shown when the assertion fails.
+    /// # Arguments
+    /// * `stmt` - stmtassert stmt to walk.
+    fn visit_assert_stmt(&mut self, stmt: &ast::stmt_ast::StmtAssert) {
+        let (result, operands) = match &stmt.condition {
+            Expr::Binary(binary) => {
+                let left = walk_expr(self, &binary.left);
+                let right = walk_expr(self, &binary.right);
+                let result =
+                    Interpreter::binary_operation(&binary.operator, left.clone(), right.clone());
+                (result, Some((left, right)))
+            }
+            condition => (walk_expr(self, condition), None),
+        };
+
+        if Interpreter::is_truth(result) {
+            return;
+        }
+
+        let mut message = format!("Assertion failed: {}", stmt.condition);
+        if let Some(custom) = &stmt.message {
+            message.push_str(&format!(", {}", walk_expr(self, custom)));
+        }
+        if let Some((left, right)) = operands {
+            message.push_str(&format!("\n    left: {}\n    right: {}", left, right));
+        }
+
+        App::runtime_error(stmt.keyword.line, message);
+    }
 }
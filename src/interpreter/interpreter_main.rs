@@ -1,7 +1,45 @@
-use super::environment::Environment;
+use super::{environment::SharedEnvironment, methods::MethodTable};
+use crate::{
+    ast::{expr_ast::FunctionObject, stmt_ast::Stmt},
+    token::token_main::{Token, TokenLiterals},
+};
+use std::{collections::HashMap, path::PathBuf};
 
 /// Top level interpreter struct.
 pub struct Interpreter {
-    pub globals: Box<Environment>,
-    pub environment: Box<Environment>,
+    pub environment: SharedEnvironment,
+    // value of a `return` statement, set while unwinding to the function call.
+    pub return_value: Option<TokenLiterals>,
+    // set when a `?.` short-circuits on null, the links after it
+    // up to the end of their optional chain are skipped.
+    pub short_circuit: bool,
+    // break or continue signal, set while unwinding to the loop it targets.
+    pub loop_signal: Option<LoopSignal>,
+    // deferred statements of every block being executed, innermost last.
+    pub deferred: Vec<Vec<Stmt>>,
+    // index of the first deferred frame of the function being executed.
+    pub frame_base: usize,
+    // number of try statements being executed in the current function.
+    pub try_depth: usize,
+    // if calls in tail position are eliminated.
+    pub tail_calls: bool,
+    // function, arguments and line of a call in tail position, set while
+    // unwinding to the function call which makes it in its place.
+    pub tail_call: Option<(Box<FunctionObject>, Vec<TokenLiterals>, usize)>,
+    // names of the functions currently being called, innermost last.
+    pub call_stack: Vec<Token>,
+    // limit of nested function calls.
+    pub max_depth: usize,
+    // native methods of the builtin types, by the name of the type.
+    pub methods: HashMap<&'static str, MethodTable>,
+    // directory relative paths are resolved against, the script's directory.
+    pub script_dir: PathBuf,
+}
+
+/// Signals of break and continue statements, with the optional label
+/// of the loop they target.
+#[derive(Debug)]
+pub enum LoopSignal {
+    Break(Option<String>),
+    Continue(Option<String>),
 }
@@ -0,0 +1,443 @@
+use super::{interpreter_main::Interpreter, natives::NativeModule};
+use crate::{app::app_main::App, token::token_main::TokenLiterals};
+use std::{cell::RefCell, rc::Rc};
+
+/// Parser for JSON text, keeps track of the line
+/// and column for errors.
+struct JsonParser {
+    // characters of the text.
+    chars: Vec<char>,
+    // position of the next character.
+    current: usize,
+    // line of the next character, from 1.
+    line: usize,
+    // column of the next character, from 1.
+    column: usize,
+}
+
+impl JsonParser {
+    /// Creates a parser for a JSON text.
+    /// # Arguments
+    /// * `text` - the JSON text.
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            current: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Parses the whole text as one value.
+    fn parse(&mut self) -> Result<TokenLiterals, String> {
+        self.skip_whitespace();
+        let value = self.value()?;
+        self.skip_whitespace();
+        match self.peek() {
+            Some(character) => {
+                Err(self.error(format!("unexpected '{}' after the value", character)))
+            }
+            None => Ok(value),
+        }
+    }
+
+    /// Creates an error message at the current position.
+    /// # Arguments
+    /// * `message` - what went wrong.
+    fn error(&self, message: String) -> String {
+        format!("line {}, column {}: {}", self.line, self.column, message)
+    }
+
+    /// Returns the next character without consuming it.
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.current).copied()
+    }
+
+    /// Consumes the next character and returns it.
+    fn advance(&mut self) -> Option<char> {
+        let character = self.peek()?;
+        self.current += 1;
+        if character == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(character)
+    }
+
+    /// Consumes the expected character, else returns an error.
+    /// # Arguments
+    /// * `expected` - the expected character.
+    fn consume(&mut self, expected: char) -> Result<(), String> {
+        match self.peek() {
+            Some(character) if character == expected => {
+                self.advance();
+                Ok(())
+            }
+            Some(character) => {
+                Err(self.error(format!("expected '{}', found '{}'", expected, character)))
+            }
+            None => Err(self.error(format!(
+                "expected '{}', found the end of the text",
+                expected
+            ))),
+        }
+    }
+
+    /// Skips whitespace between tokens.
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.advance();
+        }
+    }
+
+    /// Parses any value.
+    fn value(&mut self) -> Result<TokenLiterals, String> {
+        match self.peek() {
+            Some('[') => self.array(),
+            Some('{') => {
+                Err(self
+                    .error("objects are not supported, splax has no maps to hold them".to_string()))
+            }
+            Some('"') => Ok(TokenLiterals::String(self.string()?)),
+            Some('-' | '0'..='9') => self.number(),
+            Some(character) if character.is_alphabetic() => self.keyword(),
+            Some(character) => Err(self.error(format!("unexpected '{}'", character))),
+            None => Err(self.error("expected a value, found the end of the text".to_string())),
+        }
+    }
+
+    /// Parses `true`, `false` and `null`.
+    fn keyword(&mut self) -> Result<TokenLiterals, String> {
+        let (line, column) = (self.line, self.column);
+        let mut word = String::new();
+        while let Some(character) = self.peek().filter(|character| character.is_alphabetic()) {
+            word.push(character);
+            self.advance();
+        }
+
+        match word.as_str() {
+            "true" => Ok(TokenLiterals::Boolean(true)),
+            "false" => Ok(TokenLiterals::Boolean(false)),
+            "null" => Ok(TokenLiterals::Null),
+            _ => Err(format!(
+                "line {}, column {}: unexpected '{}'",
+                line, column, word
+            )),
+        }
+    }
+
+    /// Parses numbers, `-12.5e3`.
+    fn number(&mut self) -> Result<TokenLiterals, String> {
+        let (line, column) = (self.line, self.column);
+        let mut text = String::new();
+        while let Some(character) = self
+            .peek()
+            .filter(|character| matches!(character, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+        {
+            text.push(character);
+            self.advance();
+        }
+
+        match text.parse::<f64>() {
+            Ok(number) if JsonParser::is_number(&text) => Ok(TokenLiterals::Number(number)),
+            _ => Err(format!(
+                "line {}, column {}: invalid number '{}'",
+                line, column, text
+            )),
+        }
+    }
+
+    /// Checks a number follows the JSON grammar, which is stricter than rust's,
+    /// no leading zeros and digits are required after `.` and the exponent.
+    /// # Arguments
+    /// * `text` - text of the number.
+    fn is_number(text: &str) -> bool {
+        let mut chars = text.chars().peekable();
+        chars.next_if_eq(&'-');
+
+        // integer part, a single zero or digits without a leading zero.
+        match chars.next() {
+            Some('0') => {}
+            Some('1'..='9') => while chars.next_if(char::is_ascii_digit).is_some() {},
+            _ => return false,
+        }
+
+        // fraction part.
+        if chars.next_if_eq(&'.').is_some() {
+            if chars.next_if(char::is_ascii_digit).is_none() {
+                return false;
+            }
+            while chars.next_if(char::is_ascii_digit).is_some() {}
+        }
+
+        // exponent part.
+        if chars
+            .next_if(|character| matches!(character, 'e' | 'E'))
+            .is_some()
+        {
+            chars.next_if(|character| matches!(character, '+' | '-'));
+            if chars.next_if(char::is_ascii_digit).is_none() {
+                return false;
+            }
+            while chars.next_if(char::is_ascii_digit).is_some() {}
+        }
+
+        chars.next().is_none()
+    }
+
+    /// Parses strings, with their escape sequences.
+    fn string(&mut self) -> Result<String, String> {
+        self.consume('"')?;
+        let mut value = String::new();
+        loop {
+            match self.advance() {
+                Some('"') => return Ok(value),
+                Some('\\') => value.push(self.escape()?),
+                Some(character) if character < ' ' => {
+                    return Err(
+                        self.error("control characters must be escaped in strings".to_string())
+                    )
+                }
+                Some(character) => value.push(character),
+                None => return Err(self.error("unterminated string".to_string())),
+            }
+        }
+    }
+
+    /// Parses the character after a `\` in a string.
+    fn escape(&mut self) -> Result<char, String> {
+        match self.advance() {
+            Some('"') => Ok('"'),
+            Some('\\') => Ok('\\'),
+            Some('/') => Ok('/'),
+            Some('b') => Ok('\u{8}'),
+            Some('f') => Ok('\u{c}'),
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('u') => {
+                let high = self.hex_code()?;
+                // characters outside the basic plane are written as two escapes.
+                if (0xD800..0xDC00).contains(&high) {
+                    self.consume('\\')?;
+                    self.consume('u')?;
+                    let low = self.hex_code()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error("invalid unicode escape".to_string()));
+                    }
+                    let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                    return char::from_u32(code)
+                        .ok_or_else(|| self.error("invalid unicode escape".to_string()));
+                }
+                char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape".to_string()))
+            }
+            Some(character) => Err(self.error(format!("invalid escape '\\{}'", character))),
+            None => Err(self.error("unterminated string".to_string())),
+        }
+    }
+
+    /// Parses the 4 hex digits of a `\u` escape.
+    fn hex_code(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+        for _ in 0..4 {
+            match self.peek().and_then(|character| character.to_digit(16)) {
+                Some(digit) => {
+                    code = code * 16 + digit;
+                    self.advance();
+                }
+                None => return Err(self.error("expected 4 hex digits after '\\u'".to_string())),
+            }
+        }
+        Ok(code)
+    }
+
+    /// Parses arrays into lists.
+    fn array(&mut self) -> Result<TokenLiterals, String> {
+        self.consume('[')?;
+        let mut elements = vec![];
+
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.advance();
+            return Ok(TokenLiterals::new_list(elements));
+        }
+
+        loop {
+            self.skip_whitespace();
+            elements.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.advance();
+                }
+                Some(']') => {
+                    self.advance();
+                    return Ok(TokenLiterals::new_list(elements));
+                }
+                Some(character) => {
+                    return Err(self.error(format!("expected ',' or ']', found '{}'", character)))
+                }
+                None => {
+                    return Err(
+                        self.error("expected ',' or ']', found the end of the text".to_string())
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl Interpreter {
+    /// Creates the `json` module.
+    pub fn json_module() -> NativeModule {
+        let mut json = NativeModule::new("json");
+
+        json.function("parse", 1, Interpreter::json_parse);
+        json.rest_function("stringify", 1, Interpreter::json_stringify);
+
+        json
+    }
+
+    /// `json.parse(text)`, converts JSON text to a value.
+    /// Arrays become lists.
+    fn json_parse(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let text = Interpreter::expect_string("json.parse", "text", &arguments[0], line);
+        match JsonParser::new(&text).parse() {
+            Ok(value) => value,
+            Err(message) => {
+                App::runtime_error(line, format!("Invalid JSON at {}.", message));
+                panic!()
+            }
+        }
+    }
+
+    /// `json.stringify(value, indent)`, converts a value to JSON text.
+    /// Arrays are indented by `indent` spaces per level, a missing indent,
+    /// null or 0 keeps them on one line.
+    fn json_stringify(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        if arguments.len() > 2 {
+            App::runtime_error(
+                line,
+                format!(
+                    "'json.stringify' takes at most 2 arguments, got {}.",
+                    arguments.len()
+                ),
+            );
+        }
+
+        let indent = match arguments.get(1).unwrap_or(&TokenLiterals::Null) {
+            TokenLiterals::Null => 0,
+            indent => {
+                let indent = Interpreter::expect_number("json.stringify", "indent", indent, line);
+                if indent.fract() != 0_f64 || indent < 0_f64 {
+                    App::runtime_error(
+                        line,
+                        format!(
+                            "'json.stringify' expects a whole, non negative number for parameter 'indent', got {}.",
+                            indent
+                        ),
+                    );
+                }
+                indent as usize
+            }
+        };
+
+        let mut text = String::new();
+        if let Err(message) =
+            Interpreter::write_json(&mut text, &arguments[0], indent, 0, &mut vec![])
+        {
+            App::runtime_error(line, format!("Can't convert to JSON, {}.", message));
+        }
+        TokenLiterals::String(text)
+    }
+
+    /// Writes a value as JSON text.
+    /// # Arguments
+    /// * `text` - text to write to.
+    /// * `value` - the value.
+    /// * `indent` - spaces per level of nesting, 0 for a single line.
+    /// * `depth` - current level of nesting.
+    /// * `parents` - lists being written, to find lists containing themselves.
+    fn write_json(
+        text: &mut String,
+        value: &TokenLiterals,
+        indent: usize,
+        depth: usize,
+        parents: &mut Vec<Rc<RefCell<Vec<TokenLiterals>>>>,
+    ) -> Result<(), String> {
+        match value {
+            TokenLiterals::Null => text.push_str("null"),
+            TokenLiterals::Boolean(value) => text.push_str(&value.to_string()),
+            TokenLiterals::Number(value) if value.is_finite() => text.push_str(&value.to_string()),
+            TokenLiterals::Number(value) => {
+                return Err(format!("{} is not a valid JSON number", value));
+            }
+            TokenLiterals::String(value) => Interpreter::write_json_string(text, value),
+            TokenLiterals::List(elements) => {
+                if parents.iter().any(|parent| Rc::ptr_eq(parent, elements)) {
+                    return Err("the list contains itself".to_string());
+                }
+
+                let elements = Rc::clone(elements);
+                parents.push(Rc::clone(&elements));
+                text.push('[');
+                for (index, element) in elements.borrow().iter().enumerate() {
+                    if index > 0 {
+                        text.push(',');
+                    }
+                    if indent > 0 {
+                        text.push('\n');
+                        text.push_str(&" ".repeat(indent * (depth + 1)));
+                    }
+                    Interpreter::write_json(text, element, indent, depth + 1, parents)?;
+                }
+                if indent > 0 && !elements.borrow().is_empty() {
+                    text.push('\n');
+                    text.push_str(&" ".repeat(indent * depth));
+                }
+                text.push(']');
+                parents.pop();
+            }
+            value => {
+                return Err(format!(
+                    "a '{}' has no JSON representation",
+                    value.type_name()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a string as a JSON string, escaping quotes and control characters.
+    /// # Arguments
+    /// * `text` - text to write to.
+    /// * `value` - the string.
+    fn write_json_string(text: &mut String, value: &str) {
+        text.push('"');
+        for character in value.chars() {
+            match character {
+                '"' => text.push_str("\\\""),
+                '\\' => text.push_str("\\\\"),
+                '\n' => text.push_str("\\n"),
+                '\r' => text.push_str("\\r"),
+                '\t' => text.push_str("\\t"),
+                character if character < ' ' => {
+                    text.push_str(&format!("\\u{:04x}", character as u32))
+                }
+                character => text.push(character),
+            }
+        }
+        text.push('"');
+    }
+}
@@ -0,0 +1,169 @@
+use super::{interpreter_main::Interpreter, natives::NativeModule};
+use crate::token::token_main::TokenLiterals;
+use std::f64::consts;
+
+impl Interpreter {
+    /// Creates the `math` module.
+    pub fn math_module() -> NativeModule {
+        let mut math = NativeModule::new("math");
+
+        math.constant("pi", TokenLiterals::Number(consts::PI));
+        math.constant("e", TokenLiterals::Number(consts::E));
+
+        math.function("sqrt", 1, Interpreter::math_sqrt);
+        math.function("pow", 2, Interpreter::math_pow);
+        math.function("abs", 1, Interpreter::math_abs);
+        math.function("floor", 1, Interpreter::math_floor);
+        math.function("ceil", 1, Interpreter::math_ceil);
+        math.function("round", 1, Interpreter::math_round);
+        math.function("min", 2, Interpreter::math_min);
+        math.function("max", 2, Interpreter::math_max);
+        math.function("sin", 1, Interpreter::math_sin);
+        math.function("cos", 1, Interpreter::math_cos);
+        math.function("tan", 1, Interpreter::math_tan);
+        math.function("atan2", 2, Interpreter::math_atan2);
+        math.function("log", 1, Interpreter::math_log);
+        math.function("exp", 1, Interpreter::math_exp);
+        math.function("is_nan", 1, Interpreter::math_is_nan);
+        math.function("is_finite", 1, Interpreter::math_is_finite);
+
+        math
+    }
+
+    /// Applies a function to the only argument `x` of a math function.
+    /// # Arguments
+    /// * `name` - name of the math function.
+    /// * `arguments` - arguments of the call.
+    /// * `line` - line of the call.
+    /// * `operation` - the operation on the number.
+    fn math_unary(
+        name: &str,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+        operation: fn(f64) -> f64,
+    ) -> TokenLiterals {
+        let x = Interpreter::expect_number(name, "x", &arguments[0], line);
+        TokenLiterals::Number(operation(x))
+    }
+
+    /// Applies a function to both arguments of a math function.
+    /// # Arguments
+    /// * `name` - name of the math function.
+    /// * `parameters` - names of the two parameters.
+    /// * `arguments` - arguments of the call.
+    /// * `line` - line of the call.
+    /// * `operation` - the operation on the numbers.
+    fn math_binary(
+        name: &str,
+        parameters: (&str, &str),
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+        operation: fn(f64, f64) -> f64,
+    ) -> TokenLiterals {
+        let left = Interpreter::expect_number(name, parameters.0, &arguments[0], line);
+        let right = Interpreter::expect_number(name, parameters.1, &arguments[1], line);
+        TokenLiterals::Number(operation(left, right))
+    }
+
+    /// `math.sqrt(x)`, square root, NaN for negative numbers.
+    fn math_sqrt(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.sqrt", arguments, line, f64::sqrt)
+    }
+
+    /// `math.pow(base, exponent)`, same as `base ** exponent`.
+    fn math_pow(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_binary("math.pow", ("base", "exponent"), arguments, line, f64::powf)
+    }
+
+    /// `math.abs(x)`, absolute value.
+    fn math_abs(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.abs", arguments, line, f64::abs)
+    }
+
+    /// `math.floor(x)`, largest whole number less than or equal to x.
+    fn math_floor(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        Interpreter::math_unary("math.floor", arguments, line, f64::floor)
+    }
+
+    /// `math.ceil(x)`, smallest whole number greater than or equal to x.
+    fn math_ceil(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.ceil", arguments, line, f64::ceil)
+    }
+
+    /// `math.round(x)`, nearest whole number, halves round away from zero.
+    fn math_round(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        Interpreter::math_unary("math.round", arguments, line, f64::round)
+    }
+
+    /// `math.min(a, b)`, smaller of the two numbers.
+    fn math_min(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_binary("math.min", ("a", "b"), arguments, line, f64::min)
+    }
+
+    /// `math.max(a, b)`, larger of the two numbers.
+    fn math_max(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_binary("math.max", ("a", "b"), arguments, line, f64::max)
+    }
+
+    /// `math.sin(x)`, sine of x in radians.
+    fn math_sin(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.sin", arguments, line, f64::sin)
+    }
+
+    /// `math.cos(x)`, cosine of x in radians.
+    fn math_cos(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.cos", arguments, line, f64::cos)
+    }
+
+    /// `math.tan(x)`, tangent of x in radians.
+    fn math_tan(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.tan", arguments, line, f64::tan)
+    }
+
+    /// `math.atan2(y, x)`, angle of the point (x, y) in radians.
+    fn math_atan2(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        Interpreter::math_binary("math.atan2", ("y", "x"), arguments, line, f64::atan2)
+    }
+
+    /// `math.log(x)`, natural logarithm.
+    fn math_log(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.log", arguments, line, f64::ln)
+    }
+
+    /// `math.exp(x)`, e raised to x.
+    fn math_exp(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        Interpreter::math_unary("math.exp", arguments, line, f64::exp)
+    }
+
+    /// `math.is_nan(x)`, if x is not a number, like `math.sqrt(-1)`.
+    fn math_is_nan(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let x = Interpreter::expect_number("math.is_nan", "x", &arguments[0], line);
+        TokenLiterals::Boolean(x.is_nan())
+    }
+
+    /// `math.is_finite(x)`, if x is neither infinite nor NaN.
+    fn math_is_finite(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let x = Interpreter::expect_number("math.is_finite", "x", &arguments[0], line);
+        TokenLiterals::Boolean(x.is_finite())
+    }
+}
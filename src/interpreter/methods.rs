@@ -0,0 +1,226 @@
+use super::{
+    interpreter_main::Interpreter,
+    natives::{NativeFn, NativeFunction, NativeModule},
+};
+use crate::{
+    app::app_main::App,
+    ast::expr_ast::SplaxCallable,
+    token::token_main::{Token, TokenLiterals},
+};
+use std::collections::HashMap;
+
+/// Native methods of a runtime type, by name.
+pub type MethodTable = HashMap<String, NativeFunction>;
+
+impl Interpreter {
+    /// Creates the native methods of the builtin types, by the name of the type.
+    /// Methods are native functions which take the value they are called on as
+    /// their first argument, so module functions double as methods.
+    pub fn builtin_methods() -> HashMap<&'static str, MethodTable> {
+        let mut methods: HashMap<&'static str, MethodTable> = HashMap::new();
+
+        // every value can be converted to a string.
+        for type_name in [
+            "Number", "String", "Boolean", "Null", "Enum", "List", "Function", "Module", "Pattern",
+        ] {
+            Interpreter::define_method(
+                &mut methods,
+                type_name,
+                "to_string",
+                1,
+                Interpreter::native_str,
+            );
+        }
+
+        // string functions which take the string first.
+        let string = Interpreter::string_module();
+        for name in [
+            "len",
+            "slice",
+            "upper",
+            "lower",
+            "trim",
+            "split",
+            "replace",
+            "contains",
+            "starts_with",
+            "ends_with",
+            "find",
+            "repeat",
+            "chars",
+        ] {
+            Interpreter::method_from_module(&mut methods, "String", &string, name);
+        }
+
+        // rounding and the like for numbers.
+        let math = Interpreter::math_module();
+        for name in ["abs", "floor", "ceil", "round", "sqrt"] {
+            Interpreter::method_from_module(&mut methods, "Number", &math, name);
+        }
+
+        Interpreter::define_method(&mut methods, "List", "len", 1, Interpreter::native_len);
+        Interpreter::define_method(&mut methods, "List", "push", 2, Interpreter::list_push);
+        Interpreter::define_method(&mut methods, "List", "pop", 1, Interpreter::list_pop);
+        Interpreter::define_method(
+            &mut methods,
+            "List",
+            "contains",
+            2,
+            Interpreter::list_contains,
+        );
+        Interpreter::method_from_module(&mut methods, "List", &string, "join");
+
+        // compiled patterns.
+        let re = Interpreter::re_module();
+        for name in ["match", "find_all", "replace", "split"] {
+            Interpreter::method_from_module(&mut methods, "Pattern", &re, name);
+        }
+
+        methods
+    }
+
+    /// Defines a native method of a type.
+    /// # Arguments
+    /// * `methods` - method tables of all types.
+    /// * `type_name` - name of the type the method belongs to.
+    /// * `name` - name of the method.
+    /// * `arity` - number of arguments, including the value the method is called on.
+    /// * `function` - the rust implementation.
+    fn define_method(
+        methods: &mut HashMap<&'static str, MethodTable>,
+        type_name: &'static str,
+        name: &str,
+        arity: usize,
+        function: NativeFn,
+    ) {
+        methods.entry(type_name).or_default().insert(
+            name.to_string(),
+            NativeFunction {
+                name: format!("{}.{}", type_name, name),
+                arity,
+                rest: false,
+                function,
+            },
+        );
+    }
+
+    /// Defines a function of a native module as a method of a type.
+    /// # Arguments
+    /// * `methods` - method tables of all types.
+    /// * `type_name` - name of the type the method belongs to.
+    /// * `module` - the module with the function.
+    /// * `name` - name of the function, and the method.
+    fn method_from_module(
+        methods: &mut HashMap<&'static str, MethodTable>,
+        type_name: &'static str,
+        module: &NativeModule,
+        name: &str,
+    ) {
+        if let Some(TokenLiterals::Native(function)) = module.members.get(name) {
+            Interpreter::define_method(methods, type_name, name, function.arity, function.function);
+        }
+    }
+
+    /// Calls a method on a value, `object.name(arguments)`.
+    /// Members of modules are looked up in the module instead.
+    /// # Arguments
+    /// * `object` - value the method is called on.
+    /// * `name` - name of the method.
+    /// * `arguments` - evaluated arguments of the call.
+    /// * `line` - line of the call, for error reporting.
+    pub fn call_method(
+        &mut self,
+        object: TokenLiterals,
+        name: &Token,
+        mut arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        if let TokenLiterals::Module(module) = &object {
+            let member = Interpreter::get_member(module, name);
+            return self.call_value(member, arguments, line);
+        }
+
+        let type_name = object.type_name();
+        let Some(method) = self
+            .methods
+            .get(type_name)
+            .and_then(|methods| methods.get(&name.lexeme))
+            .cloned()
+        else {
+            let mut available: Vec<&String> = match self.methods.get(type_name) {
+                Some(methods) => methods.keys().collect(),
+                None => vec![],
+            };
+            available.sort();
+            let available: Vec<&str> = available.iter().map(|name| name.as_str()).collect();
+
+            App::runtime_error(
+                name.line,
+                format!(
+                    "'{}' has no method '{}', available methods are: {}.",
+                    type_name,
+                    name.lexeme,
+                    available.join(", ")
+                ),
+            );
+            panic!()
+        };
+
+        // the value the method is called on isn't counted as an argument.
+        if arguments.len() + 1 != method.arity {
+            App::runtime_error(
+                line,
+                format!(
+                    "'{}' expects {} arguments got {}.",
+                    method.name,
+                    method.arity - 1,
+                    arguments.len()
+                ),
+            );
+        }
+
+        arguments.insert(0, object);
+        method.call(self, arguments, line)
+    }
+
+    /// `list.push(value)`, adds a value to the end of the list.
+    fn list_push(_: &mut Interpreter, arguments: Vec<TokenLiterals>, _: usize) -> TokenLiterals {
+        if let TokenLiterals::List(elements) = &arguments[0] {
+            elements.borrow_mut().push(arguments[1].clone());
+        }
+        TokenLiterals::Null
+    }
+
+    /// `list.pop()`, removes and returns the last value of the list.
+    fn list_pop(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        let TokenLiterals::List(elements) = &arguments[0] else {
+            panic!()
+        };
+
+        let last = elements.borrow_mut().pop();
+        match last {
+            Some(last) => last,
+            None => {
+                App::runtime_error(line, "Can't pop from an empty list.".to_string());
+                panic!()
+            }
+        }
+    }
+
+    /// `list.contains(value)`, if the list has an element equal to the value.
+    fn list_contains(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        _: usize,
+    ) -> TokenLiterals {
+        let TokenLiterals::List(elements) = &arguments[0] else {
+            panic!()
+        };
+
+        let found = elements
+            .borrow()
+            .iter()
+            .any(|element| Interpreter::is_equal(element, &arguments[1]));
+        TokenLiterals::Boolean(found)
+    }
+}
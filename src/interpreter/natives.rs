@@ -0,0 +1,394 @@
+use super::{environment::Environment, interpreter_main::Interpreter};
+use crate::{
+    app::app_main::App,
+    ast::expr_ast::SplaxCallable,
+    interpreter::environment::SplaxDeclarations,
+    token::token_main::{Token, TokenLiterals},
+};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Signature of rust functions callable from splax,
+/// they get the evaluated arguments and the line of the call for errors.
+pub type NativeFn = fn(&mut Interpreter, Vec<TokenLiterals>, usize) -> TokenLiterals;
+
+/// Functions implemented in rust, callable from splax.
+#[derive(Debug, Clone)]
+pub struct NativeFunction {
+    // name the function is defined as.
+    pub name: String,
+    // number of arguments the function takes.
+    pub arity: usize,
+    // if extra arguments are accepted after the first `arity` ones.
+    pub rest: bool,
+    // the rust implementation.
+    pub function: NativeFn,
+}
+
+impl SplaxCallable for NativeFunction {
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let arity_mismatch = match self.rest {
+            true => arguments.len() < self.arity,
+            false => arguments.len() != self.arity,
+        };
+        if arity_mismatch {
+            App::runtime_error(
+                line,
+                format!(
+                    "'{}' expects {}{} arguments got {}.",
+                    self.name,
+                    if self.rest { "at least " } else { "" },
+                    self.arity,
+                    arguments.len()
+                ),
+            );
+        }
+
+        spdlog::trace!("calling native function '{}'", self.name);
+        (self.function)(interpreter, arguments, line)
+    }
+}
+
+/// Namespace of native functions and constants, like `math`.
+#[derive(Debug)]
+pub struct NativeModule {
+    // name the module is defined as.
+    pub name: String,
+    // functions and constants of the module, by name.
+    pub members: HashMap<String, TokenLiterals>,
+}
+
+impl NativeModule {
+    /// Creates an empty module.
+    /// # Arguments
+    /// * `name` - name of the module.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            members: HashMap::new(),
+        }
+    }
+
+    /// Adds a native function to the module, it is named `module.name` in errors.
+    /// # Arguments
+    /// * `name` - name of the function.
+    /// * `arity` - number of arguments the function takes.
+    /// * `function` - the rust implementation.
+    pub fn function(&mut self, name: &str, arity: usize, function: NativeFn) {
+        self.members.insert(
+            name.to_string(),
+            TokenLiterals::Native(Box::new(NativeFunction {
+                name: format!("{}.{}", self.name, name),
+                arity,
+                rest: false,
+                function,
+            })),
+        );
+    }
+
+    /// Adds a native function which takes any number of arguments
+    /// after the first `arity` ones to the module.
+    /// # Arguments
+    /// * `name` - name of the function.
+    /// * `arity` - least number of arguments the function takes.
+    /// * `function` - the rust implementation.
+    pub fn rest_function(&mut self, name: &str, arity: usize, function: NativeFn) {
+        self.members.insert(
+            name.to_string(),
+            TokenLiterals::Native(Box::new(NativeFunction {
+                name: format!("{}.{}", self.name, name),
+                arity,
+                rest: true,
+                function,
+            })),
+        );
+    }
+
+    /// Adds a constant to the module.
+    /// # Arguments
+    /// * `name` - name of the constant.
+    /// * `value` - value of the constant.
+    pub fn constant(&mut self, name: &str, value: TokenLiterals) {
+        self.members.insert(name.to_string(), value);
+    }
+}
+
+impl Interpreter {
+    /// Defines a native module in an environment.
+    /// # Arguments
+    /// * `environment` - environment to define the module in.
+    /// * `module` - the module.
+    pub fn define_module(environment: &mut Environment, module: NativeModule) {
+        environment.define(
+            module.name.clone(),
+            SplaxDeclarations::Literals(Box::new(TokenLiterals::Module(Rc::new(module)))),
+        );
+    }
+
+    /// Returns a member of a module, reports an error if it doesn't exist.
+    /// # Arguments
+    /// * `module` - the module.
+    /// * `name` - name of the member.
+    pub fn get_member(module: &NativeModule, name: &Token) -> TokenLiterals {
+        match module.members.get(&name.lexeme) {
+            Some(member) => member.clone(),
+            None => {
+                App::runtime_error(
+                    name.line,
+                    format!("Module '{}' has no member '{}'.", module.name, name.lexeme),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// Returns the number passed to a parameter of a native function,
+    /// other types are reported naming the function and the parameter.
+    /// # Arguments
+    /// * `function` - name of the native function.
+    /// * `parameter` - name of the parameter.
+    /// * `value` - the argument.
+    /// * `line` - line of the call.
+    pub fn expect_number(
+        function: &str,
+        parameter: &str,
+        value: &TokenLiterals,
+        line: usize,
+    ) -> f64 {
+        match value {
+            TokenLiterals::Number(value) => *value,
+            value => {
+                App::runtime_error(
+                    line,
+                    format!(
+                        "'{}' expects a 'Number' for parameter '{}', got '{}'.",
+                        function,
+                        parameter,
+                        value.type_name()
+                    ),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// Returns the string passed to a parameter of a native function,
+    /// other types are reported naming the function and the parameter.
+    /// # Arguments
+    /// * `function` - name of the native function.
+    /// * `parameter` - name of the parameter.
+    /// * `value` - the argument.
+    /// * `line` - line of the call.
+    pub fn expect_string(
+        function: &str,
+        parameter: &str,
+        value: &TokenLiterals,
+        line: usize,
+    ) -> String {
+        match value {
+            TokenLiterals::String(value) => value.clone(),
+            value => {
+                App::runtime_error(
+                    line,
+                    format!(
+                        "'{}' expects a 'String' for parameter '{}', got '{}'.",
+                        function,
+                        parameter,
+                        value.type_name()
+                    ),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// Returns the elements of the list passed to a parameter of a native function,
+    /// other types are reported naming the function and the parameter.
+    /// # Arguments
+    /// * `function` - name of the native function.
+    /// * `parameter` - name of the parameter.
+    /// * `value` - the argument.
+    /// * `line` - line of the call.
+    pub fn expect_list(
+        function: &str,
+        parameter: &str,
+        value: &TokenLiterals,
+        line: usize,
+    ) -> Vec<TokenLiterals> {
+        match value {
+            TokenLiterals::List(elements) => elements.borrow().clone(),
+            value => {
+                App::runtime_error(
+                    line,
+                    format!(
+                        "'{}' expects a 'List' for parameter '{}', got '{}'.",
+                        function,
+                        parameter,
+                        value.type_name()
+                    ),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// Defines a native function in an environment.
+    /// # Arguments
+    /// * `environment` - environment to define the function in.
+    /// * `name` - name of the function.
+    /// * `arity` - number of arguments the function takes.
+    /// * `function` - the rust implementation.
+    pub fn define_native(
+        environment: &mut Environment,
+        name: &str,
+        arity: usize,
+        function: NativeFn,
+    ) {
+        environment.define(
+            name.to_string(),
+            SplaxDeclarations::Natives(Box::new(NativeFunction {
+                name: name.to_string(),
+                arity,
+                rest: false,
+                function,
+            })),
+        );
+    }
+
+    /// Defines a native function which takes any number of arguments
+    /// after the first `arity` ones.
+    /// # Arguments
+    /// * `environment` - environment to define the function in.
+    /// * `name` - name of the function.
+    /// * `arity` - least number of arguments the function takes.
+    /// * `function` - the rust implementation.
+    pub fn define_rest_native(
+        environment: &mut Environment,
+        name: &str,
+        arity: usize,
+        function: NativeFn,
+    ) {
+        environment.define(
+            name.to_string(),
+            SplaxDeclarations::Natives(Box::new(NativeFunction {
+                name: name.to_string(),
+                arity,
+                rest: true,
+                function,
+            })),
+        );
+    }
+
+    /// Defines the builtin native functions.
+    /// # Arguments
+    /// * `globals` - the global environment.
+    pub fn define_builtins(globals: &mut Environment) {
+        Interpreter::define_native(globals, "clock", 0, Interpreter::native_clock);
+        Interpreter::define_native(globals, "input", 1, Interpreter::native_input);
+        Interpreter::define_native(globals, "len", 1, Interpreter::native_len);
+        Interpreter::define_native(globals, "str", 1, Interpreter::native_str);
+        Interpreter::define_native(globals, "num", 1, Interpreter::native_num);
+        Interpreter::define_native(globals, "type", 1, Interpreter::native_type);
+        Interpreter::define_rest_native(globals, "format", 1, Interpreter::native_format);
+
+        Interpreter::define_fs(globals);
+        Interpreter::define_process(globals);
+
+        Interpreter::define_module(globals, Interpreter::math_module());
+        Interpreter::define_module(globals, Interpreter::string_module());
+        Interpreter::define_module(globals, Interpreter::json_module());
+        Interpreter::define_module(globals, Interpreter::re_module());
+    }
+
+    /// `clock()`, seconds since the unix epoch.
+    fn native_clock(_: &mut Interpreter, _: Vec<TokenLiterals>, _: usize) -> TokenLiterals {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(time) => TokenLiterals::Number(time.as_secs_f64()),
+            Err(_) => TokenLiterals::Number(0_f64),
+        }
+    }
+
+    /// `input(prompt)`, prints the prompt and reads a line from stdin,
+    /// without the line ending. Returns null at the end of input.
+    fn native_input(_: &mut Interpreter, arguments: Vec<TokenLiterals>, _: usize) -> TokenLiterals {
+        print!("{}", arguments[0]);
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        match io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) => TokenLiterals::Null,
+            Ok(_) => TokenLiterals::String(line.trim_end_matches(['\n', '\r']).to_string()),
+        }
+    }
+
+    /// `len(x)`, number of characters in a string or elements in a list.
+    pub fn native_len(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        match &arguments[0] {
+            TokenLiterals::String(value) => TokenLiterals::Number(value.chars().count() as f64),
+            TokenLiterals::List(elements) => TokenLiterals::Number(elements.borrow().len() as f64),
+            value => {
+                App::runtime_error(
+                    line,
+                    format!(
+                        "'len' expects a 'String' or a 'List', got '{}'.",
+                        value.type_name()
+                    ),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// `str(x)`, any value as a string, the same as it is printed.
+    pub fn native_str(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        _: usize,
+    ) -> TokenLiterals {
+        TokenLiterals::String(arguments[0].to_string())
+    }
+
+    /// `num(x)`, converts strings and booleans to numbers.
+    /// Returns null for strings which aren't numbers.
+    fn native_num(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        match &arguments[0] {
+            TokenLiterals::Number(value) => TokenLiterals::Number(*value),
+            TokenLiterals::String(value) => match value.trim().parse::<f64>() {
+                Ok(value) => TokenLiterals::Number(value),
+                Err(_) => TokenLiterals::Null,
+            },
+            TokenLiterals::Boolean(value) => {
+                TokenLiterals::Number(if *value { 1_f64 } else { 0_f64 })
+            }
+            value => {
+                App::runtime_error(
+                    line,
+                    format!("Can't convert '{}' to a 'Number'.", value.type_name()),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// `type(x)`, name of the type of a value.
+    fn native_type(_: &mut Interpreter, arguments: Vec<TokenLiterals>, _: usize) -> TokenLiterals {
+        TokenLiterals::String(arguments[0].type_name().to_string())
+    }
+}
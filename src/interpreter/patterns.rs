@@ -0,0 +1,165 @@
+use super::{interpreter_main::Interpreter, natives::NativeModule};
+use crate::{app::app_main::App, token::token_main::TokenLiterals};
+use regex::{Captures, Regex};
+
+impl Interpreter {
+    /// Creates the `re` module, for regular expressions.
+    /// Functions taking a pattern accept a compiled pattern or a string.
+    pub fn re_module() -> NativeModule {
+        let mut re = NativeModule::new("re");
+
+        re.function("compile", 1, Interpreter::re_compile);
+        re.function("match", 2, Interpreter::re_match);
+        re.function("find_all", 2, Interpreter::re_find_all);
+        re.function("replace", 3, Interpreter::re_replace);
+        re.function("split", 2, Interpreter::re_split);
+
+        re
+    }
+
+    /// Compiles a regular expression, reports invalid patterns
+    /// with the position of the problem.
+    /// # Arguments
+    /// * `pattern` - source of the regular expression.
+    /// * `line` - line of the call.
+    fn compile_pattern(pattern: &str, line: usize) -> Regex {
+        match Regex::new(pattern) {
+            Ok(regex) => regex,
+            Err(error) => {
+                // the syntax parser knows where the problem is, the compiled regex only
+                // gives a message meant to be printed on its own.
+                let span = match regex_syntax::Parser::new().parse(pattern) {
+                    Err(regex_syntax::Error::Parse(error)) => {
+                        Some((error.kind().to_string(), error.span().start.offset))
+                    }
+                    Err(regex_syntax::Error::Translate(error)) => {
+                        Some((error.kind().to_string(), error.span().start.offset))
+                    }
+                    _ => None,
+                };
+                let message = match span {
+                    Some((kind, offset)) => format!(
+                        "Invalid pattern '{}' at position {}: {}.",
+                        pattern,
+                        pattern[..offset].chars().count(),
+                        kind
+                    ),
+                    None => format!("Invalid pattern '{}': {}.", pattern, error),
+                };
+                App::runtime_error(line, message);
+                panic!()
+            }
+        }
+    }
+
+    /// Returns the pattern passed to a function of the `re` module,
+    /// strings are compiled.
+    /// # Arguments
+    /// * `function` - name of the function.
+    /// * `value` - the pattern argument.
+    /// * `line` - line of the call.
+    fn expect_pattern(function: &str, value: &TokenLiterals, line: usize) -> Regex {
+        match value {
+            TokenLiterals::Pattern(regex) => regex.clone(),
+            TokenLiterals::String(pattern) => Interpreter::compile_pattern(pattern, line),
+            value => {
+                App::runtime_error(
+                    line,
+                    format!(
+                        "'{}' expects a 'Pattern' or a 'String' for parameter 'pattern', got '{}'.",
+                        function,
+                        value.type_name()
+                    ),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// Converts a match to a list, the whole match followed by
+    /// its groups, null for groups which didn't match.
+    /// # Arguments
+    /// * `captures` - the match.
+    fn captures_list(captures: &Captures) -> TokenLiterals {
+        TokenLiterals::new_list(
+            captures
+                .iter()
+                .map(|group| match group {
+                    Some(group) => TokenLiterals::String(group.as_str().to_string()),
+                    None => TokenLiterals::Null,
+                })
+                .collect(),
+        )
+    }
+
+    /// `re.compile(pattern)`, a pattern which can be reused without compiling it again.
+    fn re_compile(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let pattern = Interpreter::expect_string("re.compile", "pattern", &arguments[0], line);
+        TokenLiterals::Pattern(Interpreter::compile_pattern(&pattern, line))
+    }
+
+    /// `re.match(pattern, s)`, first match in s as a list of the whole
+    /// match and its groups, null if nothing matches.
+    fn re_match(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        let regex = Interpreter::expect_pattern("re.match", &arguments[0], line);
+        let value = Interpreter::expect_string("re.match", "s", &arguments[1], line);
+        match regex.captures(&value) {
+            Some(captures) => Interpreter::captures_list(&captures),
+            None => TokenLiterals::Null,
+        }
+    }
+
+    /// `re.find_all(pattern, s)`, every match in s. Matches are strings
+    /// for patterns without groups, else lists like the ones of `re.match`.
+    fn re_find_all(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let regex = Interpreter::expect_pattern("re.find_all", &arguments[0], line);
+        let value = Interpreter::expect_string("re.find_all", "s", &arguments[1], line);
+
+        let matches = if regex.captures_len() == 1 {
+            regex
+                .find_iter(&value)
+                .map(|found| TokenLiterals::String(found.as_str().to_string()))
+                .collect()
+        } else {
+            regex
+                .captures_iter(&value)
+                .map(|captures| Interpreter::captures_list(&captures))
+                .collect()
+        };
+        TokenLiterals::new_list(matches)
+    }
+
+    /// `re.replace(pattern, s, replacement)`, s with every match replaced.
+    /// `$1` or `${name}` in the replacement refer to groups, `$$` is a `$`.
+    fn re_replace(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let regex = Interpreter::expect_pattern("re.replace", &arguments[0], line);
+        let value = Interpreter::expect_string("re.replace", "s", &arguments[1], line);
+        let replacement =
+            Interpreter::expect_string("re.replace", "replacement", &arguments[2], line);
+        TokenLiterals::String(regex.replace_all(&value, replacement.as_str()).to_string())
+    }
+
+    /// `re.split(pattern, s)`, list of the parts of s between matches.
+    fn re_split(_: &mut Interpreter, arguments: Vec<TokenLiterals>, line: usize) -> TokenLiterals {
+        let regex = Interpreter::expect_pattern("re.split", &arguments[0], line);
+        let value = Interpreter::expect_string("re.split", "s", &arguments[1], line);
+        TokenLiterals::new_list(
+            regex
+                .split(&value)
+                .map(|part| TokenLiterals::String(part.to_string()))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,71 @@
+use super::{environment::Environment, interpreter_main::Interpreter};
+use crate::{app::app_main::App, token::token_main::TokenLiterals};
+use std::{env, process};
+
+impl Interpreter {
+    /// Defines the native functions for the process running the script.
+    /// # Arguments
+    /// * `globals` - the global environment.
+    pub fn define_process(globals: &mut Environment) {
+        Interpreter::define_native(globals, "env", 1, Interpreter::process_env);
+        Interpreter::define_native(globals, "set_env", 2, Interpreter::process_set_env);
+        Interpreter::define_native(globals, "exit", 1, Interpreter::process_exit);
+    }
+
+    /// `env(name)`, value of an environment variable, null if it isn't set.
+    fn process_env(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let name = Interpreter::expect_string("env", "name", &arguments[0], line);
+        match env::var(name) {
+            Ok(value) => TokenLiterals::String(value),
+            Err(_) => TokenLiterals::Null,
+        }
+    }
+
+    /// `set_env(name, value)`, sets an environment variable for this
+    /// process and the processes it starts.
+    fn process_set_env(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let name = Interpreter::expect_string("set_env", "name", &arguments[0], line);
+        let value = Interpreter::expect_string("set_env", "value", &arguments[1], line);
+        if name.is_empty() || name.contains(['=', '\0']) || value.contains('\0') {
+            App::runtime_error(
+                line,
+                format!(
+                    "'set_env' can't set an environment variable named '{}'.",
+                    name
+                ),
+            );
+        }
+
+        env::set_var(name, value);
+        TokenLiterals::Null
+    }
+
+    /// `exit(code)`, stops the script right away with an exit status,
+    /// deferred statements don't run.
+    fn process_exit(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let code = Interpreter::expect_number("exit", "code", &arguments[0], line);
+        if code.fract() != 0_f64 || code < i32::MIN as f64 || code > i32::MAX as f64 {
+            App::runtime_error(
+                line,
+                format!(
+                    "'exit' expects a whole number for parameter 'code', got {}.",
+                    code
+                ),
+            );
+        }
+
+        process::exit(code as i32)
+    }
+}
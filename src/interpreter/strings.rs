@@ -0,0 +1,252 @@
+use super::{interpreter_main::Interpreter, natives::NativeModule};
+use crate::{app::app_main::App, token::token_main::TokenLiterals};
+
+impl Interpreter {
+    /// Creates the `string` module.
+    /// Positions in strings count characters, not bytes.
+    pub fn string_module() -> NativeModule {
+        let mut string = NativeModule::new("string");
+
+        string.function("len", 1, Interpreter::string_len);
+        string.function("slice", 3, Interpreter::string_slice);
+        string.function("upper", 1, Interpreter::string_upper);
+        string.function("lower", 1, Interpreter::string_lower);
+        string.function("trim", 1, Interpreter::string_trim);
+        string.function("split", 2, Interpreter::string_split);
+        string.function("join", 2, Interpreter::string_join);
+        string.function("replace", 3, Interpreter::string_replace);
+        string.function("contains", 2, Interpreter::string_contains);
+        string.function("starts_with", 2, Interpreter::string_starts_with);
+        string.function("ends_with", 2, Interpreter::string_ends_with);
+        string.function("find", 2, Interpreter::string_find);
+        string.function("repeat", 2, Interpreter::string_repeat);
+        string.function("chars", 1, Interpreter::string_chars);
+
+        string
+    }
+
+    /// Returns a whole, non negative number passed to a parameter of a string function.
+    /// # Arguments
+    /// * `function` - name of the string function.
+    /// * `parameter` - name of the parameter.
+    /// * `value` - the argument.
+    /// * `line` - line of the call.
+    fn string_count(function: &str, parameter: &str, value: &TokenLiterals, line: usize) -> usize {
+        let count = Interpreter::expect_number(function, parameter, value, line);
+        // counts past 2^53 can't be represented exactly and would saturate when converted.
+        if count.fract() != 0_f64 || !(0_f64..=9007199254740992_f64).contains(&count) {
+            App::runtime_error(
+                line,
+                format!(
+                    "'{}' expects a whole, non negative number for parameter '{}', got {}.",
+                    function, parameter, count
+                ),
+            );
+        }
+        count as usize
+    }
+
+    /// `string.len(s)`, number of characters in s.
+    fn string_len(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.len", "s", &arguments[0], line);
+        TokenLiterals::Number(value.chars().count() as f64)
+    }
+
+    /// `string.slice(s, start, end)`, characters of s from start up to, but not including, end.
+    fn string_slice(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.slice", "s", &arguments[0], line);
+        let start = Interpreter::string_count("string.slice", "start", &arguments[1], line);
+        let end = Interpreter::string_count("string.slice", "end", &arguments[2], line);
+
+        let length = value.chars().count();
+        if start > end || end > length {
+            App::runtime_error(
+                line,
+                format!(
+                    "Slice {}..{} out of range for string of length {}.",
+                    start, end, length
+                ),
+            );
+        }
+
+        TokenLiterals::String(value.chars().skip(start).take(end - start).collect())
+    }
+
+    /// `string.upper(s)`, s in upper case.
+    fn string_upper(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.upper", "s", &arguments[0], line);
+        TokenLiterals::String(value.to_uppercase())
+    }
+
+    /// `string.lower(s)`, s in lower case.
+    fn string_lower(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.lower", "s", &arguments[0], line);
+        TokenLiterals::String(value.to_lowercase())
+    }
+
+    /// `string.trim(s)`, s without whitespace at the start and end.
+    fn string_trim(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.trim", "s", &arguments[0], line);
+        TokenLiterals::String(value.trim().to_string())
+    }
+
+    /// `string.split(s, separator)`, list of the parts of s between separators.
+    fn string_split(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.split", "s", &arguments[0], line);
+        let separator =
+            Interpreter::expect_string("string.split", "separator", &arguments[1], line);
+        if separator.is_empty() {
+            App::runtime_error(
+                line,
+                "'string.split' can't split by an empty separator, use 'string.chars' instead."
+                    .to_string(),
+            );
+        }
+
+        TokenLiterals::new_list(
+            value
+                .split(separator.as_str())
+                .map(|part| TokenLiterals::String(part.to_string()))
+                .collect(),
+        )
+    }
+
+    /// `string.join(list, separator)`, elements of the list as strings, with separators between them.
+    fn string_join(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let elements = Interpreter::expect_list("string.join", "list", &arguments[0], line);
+        let separator = Interpreter::expect_string("string.join", "separator", &arguments[1], line);
+
+        let parts: Vec<String> = elements.iter().map(|element| element.to_string()).collect();
+        TokenLiterals::String(parts.join(&separator))
+    }
+
+    /// `string.replace(s, from, to)`, s with every occurrence of from replaced by to.
+    fn string_replace(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.replace", "s", &arguments[0], line);
+        let from = Interpreter::expect_string("string.replace", "from", &arguments[1], line);
+        let to = Interpreter::expect_string("string.replace", "to", &arguments[2], line);
+        TokenLiterals::String(value.replace(&from, &to))
+    }
+
+    /// `string.contains(s, part)`, if part appears in s.
+    fn string_contains(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.contains", "s", &arguments[0], line);
+        let part = Interpreter::expect_string("string.contains", "part", &arguments[1], line);
+        TokenLiterals::Boolean(value.contains(&part))
+    }
+
+    /// `string.starts_with(s, prefix)`, if s starts with prefix.
+    fn string_starts_with(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.starts_with", "s", &arguments[0], line);
+        let prefix =
+            Interpreter::expect_string("string.starts_with", "prefix", &arguments[1], line);
+        TokenLiterals::Boolean(value.starts_with(&prefix))
+    }
+
+    /// `string.ends_with(s, suffix)`, if s ends with suffix.
+    fn string_ends_with(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.ends_with", "s", &arguments[0], line);
+        let suffix = Interpreter::expect_string("string.ends_with", "suffix", &arguments[1], line);
+        TokenLiterals::Boolean(value.ends_with(&suffix))
+    }
+
+    /// `string.find(s, part)`, position of the first occurrence of part in s, null if it doesn't appear.
+    fn string_find(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.find", "s", &arguments[0], line);
+        let part = Interpreter::expect_string("string.find", "part", &arguments[1], line);
+
+        match value.find(&part) {
+            // converting the byte offset into a character position.
+            Some(offset) => TokenLiterals::Number(value[..offset].chars().count() as f64),
+            None => TokenLiterals::Null,
+        }
+    }
+
+    /// `string.repeat(s, count)`, s repeated count times.
+    fn string_repeat(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.repeat", "s", &arguments[0], line);
+        let count = Interpreter::string_count("string.repeat", "count", &arguments[1], line);
+        match value.len().checked_mul(count) {
+            Some(length) if length <= App::MAX_STRING_LENGTH => {
+                TokenLiterals::String(value.repeat(count))
+            }
+            _ => {
+                App::runtime_error(
+                    line,
+                    format!(
+                        "'string.repeat' result would be longer than {} bytes.",
+                        App::MAX_STRING_LENGTH
+                    ),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// `string.chars(s)`, list of the characters of s.
+    fn string_chars(
+        _: &mut Interpreter,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        let value = Interpreter::expect_string("string.chars", "s", &arguments[0], line);
+        TokenLiterals::new_list(
+            value
+                .chars()
+                .map(|character| TokenLiterals::String(character.to_string()))
+                .collect(),
+        )
+    }
+}
@@ -1,19 +1,59 @@
-use super::{environment::Environment, interpreter_main::Interpreter};
+use super::{
+    environment::{SharedEnvironment, SplaxDeclarations},
+    interpreter_main::Interpreter,
+};
 use crate::{
     app::app_main::App,
-    ast::stmt_ast::{walk_stmt, Stmt, StmtBlock},
-    token::token_main::TokenLiterals,
+    ast::{
+        expr_ast::{walk_expr, Expr, ExprCall, FunctionObject, SplaxCallable},
+        stmt_ast::{walk_stmt, BindingPattern, ListPattern, Stmt, StmtBlock, StmtEnum},
+    },
+    token::{
+        token_main::{EnumValue, ListPointer, Token, TokenLiterals},
+        token_types::TokenType,
+    },
+};
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    rc::Rc,
 };
 
 impl Interpreter {
     /// Constructor for Interpreter.
-    pub fn new() -> Self {
+    /// # Arguments
+    /// * `tail_calls` - if calls in tail position should be eliminated.
+    /// * `max_depth` - limit of nested function calls.
+    /// * `script_dir` - directory relative paths are resolved against.
+    /// * `args` - command line arguments for the script.
+    pub fn new(tail_calls: bool, max_depth: usize, script_dir: PathBuf, args: Vec<String>) -> Self {
         spdlog::debug!("constructing new interpreter.");
-        let globals = Interpreter::get_globals();
+        let mut globals = Interpreter::get_globals();
+
+        // command line arguments of the script.
+        let args = args.into_iter().map(TokenLiterals::String).collect();
+        globals.define(
+            "args".to_string(),
+            SplaxDeclarations::Literals(Box::new(TokenLiterals::new_list(args))),
+        );
+
         // App::runtime_error(2, format!("{}", globals.get("__VERSION__")));
         Self {
-            environment: globals.clone(),
-            globals,
+            environment: Rc::new(RefCell::new(*globals)),
+            return_value: None,
+            short_circuit: false,
+            loop_signal: None,
+            deferred: vec![],
+            frame_base: 0,
+            try_depth: 0,
+            tail_calls,
+            tail_call: None,
+            call_stack: vec![],
+            max_depth,
+            methods: Interpreter::builtin_methods(),
+            script_dir,
         }
     }
 
@@ -22,36 +62,69 @@ impl Interpreter {
     /// * `statements` - A vector of statements aka a program.
     pub fn interpret(&mut self, statements: Vec<Stmt>) {
         spdlog::info!("start interpreting");
-        for statement in statements {
-            self.execute(&statement);
+        // top level defers run when the program ends, even if it ends with a runtime error.
+        self.deferred.push(vec![]);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            for statement in statements {
+                self.execute(&statement);
+            }
+        }));
+        self.run_deferred();
+        if let Err(error) = result {
+            panic::resume_unwind(error);
         }
     }
 
     /// Executes a block of statements, give it a new environment.
     /// # Arguments
-    /// * `enclosing` - The enclosing environment.
+    /// * `block_statements` - The statements of the block.
+    /// * `child_environment` - The environment to execute them in.
     pub fn execute_block(
         &mut self,
         block_statements: &StmtBlock,
-        child_environment: Box<Environment>,
+        child_environment: SharedEnvironment,
     ) {
-        // setting current env as child environment.
-        self.environment = child_environment;
+        // setting current env as child environment, the previous one is restored on exit.
+        let previous = std::mem::replace(&mut self.environment, child_environment);
+        self.deferred.push(vec![]);
 
-        // executing block statements.
-        for stmt in &block_statements.block_statements {
-            self.execute(stmt);
+        // executing block statements, stopping early if one of them returned,
+        // or broke out of a loop. runtime errors are caught so the deferred
+        // statements run before the error leaves the block.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            for stmt in &block_statements.block_statements {
+                self.execute(stmt);
+                if self.return_value.is_some() || self.loop_signal.is_some() {
+                    break;
+                }
+            }
+        }));
+
+        // deferred statements run in the block's environment, before leaving it.
+        self.run_deferred();
+        self.environment = previous;
+
+        if let Err(error) = result {
+            panic::resume_unwind(error);
         }
+    }
 
-        // take current's parent environment, clone it and make it current environment
-        if let Some(parent_environment) = self.environment.enclosing.clone() {
-            self.environment = parent_environment;
+    /// Runs the deferred statements of the innermost block, last deferred first.
+    /// A pending return value or loop signal is kept aside while they run,
+    /// so calls made by them behave normally.
+    pub fn run_deferred(&mut self) {
+        let Some(deferred) = self.deferred.pop() else {
             return;
-        }
+        };
 
-        // this is unreachable, if you somehow manage to trigger it,
-        // feel free to fix it and make a pr.
-        App::runtime_error(0, "Reassignment of environment failed.".to_string());
+        let return_value = self.return_value.take();
+        let loop_signal = self.loop_signal.take();
+        for stmt in deferred.iter().rev() {
+            spdlog::trace!("running deferred statement : {:?}", stmt);
+            self.execute(stmt);
+        }
+        self.return_value = return_value;
+        self.loop_signal = loop_signal;
     }
 
     /// Walks one statement at a time.
@@ -74,6 +147,578 @@ impl Interpreter {
             TokenLiterals::Null => false,
             // any string is true unless is empty.
             TokenLiterals::String(value) => !value.is_empty(),
+            // enum values are always true.
+            TokenLiterals::Enum(_) => true,
+            // any list is true unless is empty.
+            TokenLiterals::List(elements) => !elements.borrow().is_empty(),
+            // functions are always true.
+            TokenLiterals::Function(_) | TokenLiterals::Native(_) => true,
+            // modules and patterns are always true.
+            TokenLiterals::Module(_) | TokenLiterals::Pattern(_) => true,
+        }
+    }
+
+    /// Raises `base` to the power of `exponent`.
+    /// When both are integers and the result fits, the result is computed
+    /// with integer arithmetic so that it is exact, otherwise falls back to
+    /// floating point powers.
+    /// # Arguments
+    /// * `base` - The base number.
+    /// * `exponent` - The exponent number.
+    pub fn power(base: f64, exponent: f64) -> f64 {
+        spdlog::trace!("raising {base} to the power {exponent}");
+        if base.fract() == 0_f64
+            && exponent.fract() == 0_f64
+            && exponent >= 0_f64
+            && base.abs() <= i64::MAX as f64
+            && exponent <= u32::MAX as f64
+        {
+            if let Some(value) = (base as i64).checked_pow(exponent as u32) {
+                return value as f64;
+            }
+        }
+
+        base.powf(exponent)
+    }
+
+    /// Splax core logic which defines equality between any two values.
+    /// Values of different types are never equal, values of the same type
+    /// are compared by value.
+    /// # Arguments
+    /// * `left` - left operand.
+    /// * `right` - right operand.
+    pub fn is_equal(left: &TokenLiterals, right: &TokenLiterals) -> bool {
+        spdlog::trace!("checking equality for : {} and {}", left, right);
+        Interpreter::values_equal(left, right, &mut Vec::new())
+    }
+
+    /// Compares two values, tracking the pairs of lists being compared so that
+    /// lists containing themselves don't recurse forever, a pair that is already
+    /// being compared is assumed equal.
+    /// # Arguments
+    /// * `left` - left operand.
+    /// * `right` - right operand.
+    /// * `parents` - pairs of lists enclosing the values being compared.
+    fn values_equal(
+        left: &TokenLiterals,
+        right: &TokenLiterals,
+        parents: &mut Vec<(ListPointer, ListPointer)>,
+    ) -> bool {
+        match (left, right) {
+            (TokenLiterals::Number(left), TokenLiterals::Number(right)) => left == right,
+            (TokenLiterals::String(left), TokenLiterals::String(right)) => left == right,
+            (TokenLiterals::Boolean(left), TokenLiterals::Boolean(right)) => left == right,
+            (TokenLiterals::Null, TokenLiterals::Null) => true,
+            // enum values are equal when they are the same variant with equal fields.
+            (TokenLiterals::Enum(left), TokenLiterals::Enum(right)) => {
+                left.enum_name == right.enum_name
+                    && left.variant == right.variant
+                    && left.fields.len() == right.fields.len()
+                    && left
+                        .fields
+                        .iter()
+                        .zip(right.fields.iter())
+                        .all(|(left, right)| Interpreter::values_equal(left, right, parents))
+            }
+            // functions are equal when they are copies of the same function value,
+            // the same declaration closing over the same scope.
+            (TokenLiterals::Function(left), TokenLiterals::Function(right)) => {
+                Rc::ptr_eq(&left.declaration, &right.declaration)
+                    && Rc::ptr_eq(&left.closure, &right.closure)
+            }
+            (TokenLiterals::Native(left), TokenLiterals::Native(right)) => left.name == right.name,
+            (TokenLiterals::Module(left), TokenLiterals::Module(right)) => Rc::ptr_eq(left, right),
+            (TokenLiterals::Pattern(left), TokenLiterals::Pattern(right)) => {
+                left.as_str() == right.as_str()
+            }
+            // lists are equal when they have equal elements in the same order.
+            (TokenLiterals::List(left), TokenLiterals::List(right)) => {
+                let pair = (Rc::as_ptr(left), Rc::as_ptr(right));
+                if Rc::ptr_eq(left, right) || parents.contains(&pair) {
+                    return true;
+                }
+                parents.push(pair);
+                let (left, right) = (left.borrow(), right.borrow());
+                let equal = left.len() == right.len()
+                    && left
+                        .iter()
+                        .zip(right.iter())
+                        .all(|(left, right)| Interpreter::values_equal(left, right, parents));
+                parents.pop();
+                equal
+            }
+            _ => false,
+        }
+    }
+
+    /// Evaluates a binary operator on already evaluated operands.
+    /// # Arguments
+    /// * `operator` - the operator token.
+    /// * `left` - left operand.
+    /// * `right` - right operand.
+    pub fn binary_operation(
+        operator: &Token,
+        left: TokenLiterals,
+        right: TokenLiterals,
+    ) -> TokenLiterals {
+        // equality and comparison operators are defined for every pair of types,
+        // so they are handled before matching on operand types.
+        match operator.token_type {
+            TokenType::EqualEqual => {
+                return TokenLiterals::Boolean(Interpreter::is_equal(&left, &right))
+            }
+            TokenType::BangEqual => {
+                return TokenLiterals::Boolean(!Interpreter::is_equal(&left, &right))
+            }
+            TokenType::Greater
+            | TokenType::GreaterEqual
+            | TokenType::Less
+            | TokenType::LessEqual => {
+                return TokenLiterals::Boolean(Interpreter::compare(operator, &left, &right))
+            }
+            _ => (),
+        }
+
+        // matching left operand.
+        match left {
+            // if left operand is a number.
+            TokenLiterals::Number(left_value) => match right {
+                // when both left and right operands are numbers.
+                TokenLiterals::Number(right_value) => match operator.token_type {
+                    // Operators supported by two number operands.
+                    TokenType::Plus => TokenLiterals::Number(left_value + right_value),
+                    TokenType::Minus => TokenLiterals::Number(left_value - right_value),
+                    TokenType::Star => TokenLiterals::Number(left_value * right_value),
+                    TokenType::Slash => TokenLiterals::Number(left_value / right_value),
+                    TokenType::Mod => TokenLiterals::Number(left_value % right_value),
+                    TokenType::StarStar => {
+                        TokenLiterals::Number(Interpreter::power(left_value, right_value))
+                    }
+
+                    // any other operators are not for number.
+                    _ => {
+                        App::runtime_error(
+                            operator.line,
+                            "unsupported operator for 'Number'".to_string(),
+                        );
+                        panic!("This is unreachable, if somehow you managed to trigger this, idk.")
+                    }
+                }, // operator matching for both operands number types.
+
+                // any other type when the left operand is a number will result in a runtime errror.
+                _ => {
+                    App::runtime_error(
+                        operator.line,
+                        "unsupported operand type(s): 'Number' with a non 'Number'".to_string(),
+                    );
+                    panic!();
+                }
+            }, // left operand matching: Number,
+
+            // when left operand is a string.
+            TokenLiterals::String(left_value) => match right {
+                // when both left and right operands are strings.
+                TokenLiterals::String(right_value) => match operator.token_type {
+                    // arthematic operators for strings.
+                    TokenType::Plus => {
+                        TokenLiterals::String(format!("{}{}", left_value, right_value))
+                    }
+
+                    // any other operators are not for strings.
+                    _ => {
+                        App::runtime_error(
+                            operator.line,
+                            "unsupported operator for 'String'".to_string(),
+                        );
+                        panic!()
+                    }
+                }, // operator matching for both operands string types.
+
+                // any other type when the left operand is a string will result in a runtime errror.
+                _ => {
+                    App::runtime_error(
+                        operator.line,
+                        "unsupported operand type(s): 'String' with a non 'String'".to_string(),
+                    );
+                    panic!()
+                }
+            },
+
+            // when left literal is neither number nor string.
+            _ => {
+                App::runtime_error(
+                    operator.line,
+                    format!("unsupported operator for '{}'", left.type_name()),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// Evaluates comparison operators ( `>`, `>=`, `<`, `<=` ).
+    /// Numbers are compared numerically and strings lexicographically,
+    /// comparing any other pair of types results in a runtime error.
+    /// # Arguments
+    /// * `operator` - the comparison operator token.
+    /// * `left` - left operand.
+    /// * `right` - right operand.
+    pub fn compare(operator: &Token, left: &TokenLiterals, right: &TokenLiterals) -> bool {
+        let ordering = match (left, right) {
+            (TokenLiterals::Number(left), TokenLiterals::Number(right)) => left.partial_cmp(right),
+            (TokenLiterals::String(left), TokenLiterals::String(right)) => Some(left.cmp(right)),
+            _ => {
+                App::runtime_error(
+                    operator.line,
+                    format!(
+                        "cannot compare '{}' with '{}' using '{}'",
+                        left.type_name(),
+                        right.type_name(),
+                        operator.lexeme
+                    ),
+                );
+                panic!()
+            }
+        };
+
+        // NaN is not ordered with anything, every comparison with it is false.
+        let Some(ordering) = ordering else {
+            return false;
+        };
+
+        match operator.token_type {
+            TokenType::Greater => ordering == Ordering::Greater,
+            TokenType::GreaterEqual => ordering != Ordering::Less,
+            TokenType::Less => ordering == Ordering::Less,
+            TokenType::LessEqual => ordering != Ordering::Greater,
+            _ => false,
+        }
+    }
+
+    /// Evaluates a list of expressions, expanding spreads in place.
+    /// Spreading anything other than a list results in a runtime error.
+    /// # Arguments
+    /// * `expressions` - expressions to evaluate, may contain spreads.
+    pub fn evaluate_spread(&mut self, expressions: &[Expr]) -> Vec<TokenLiterals> {
+        let mut values = vec![];
+        for expression in expressions {
+            let Expr::Spread(spread) = expression else {
+                values.push(walk_expr(self, expression));
+                continue;
+            };
+
+            match walk_expr(self, &spread.expression) {
+                TokenLiterals::List(elements) => values.extend(elements.borrow().iter().cloned()),
+                value => {
+                    App::runtime_error(
+                        spread.operator.line,
+                        format!("Can only spread a 'List', got '{}'.", value.type_name()),
+                    );
+                    panic!()
+                }
+            }
+        }
+
+        values
+    }
+
+    /// Evaluates the arguments of a call, expanding spreads.
+    /// # Arguments
+    /// * `expr` - Call expression.
+    pub fn evaluate_arguments(&mut self, expr: &ExprCall) -> Vec<TokenLiterals> {
+        let arguments = self.evaluate_spread(&expr.arguments);
+        if arguments.len() > 255 {
+            App::runtime_error(
+                expr.paren.line,
+                "Can't have more than 255 function arguments.".to_string(),
+            );
+        }
+        arguments
+    }
+
+    /// Evaluates the value of a return statement, which is in tail position.
+    /// Calls to functions in tail position are not made here, they are handed
+    /// to the `FunctionObject::call` being returned from through `tail_call`,
+    /// which makes them instead, so they don't grow the stack.
+    /// # Arguments
+    /// * `expr` - expression in tail position.
+    pub fn evaluate_tail(&mut self, expr: &Expr) -> TokenLiterals {
+        // deferred statements have to run after the call returns, and errors
+        // of calls inside a try have to be caught, so they aren't really in tail position.
+        if !self.tail_calls
+            || self.try_depth > 0
+            || self.deferred[self.frame_base..]
+                .iter()
+                .any(|deferred| !deferred.is_empty())
+        {
+            return walk_expr(self, expr);
+        }
+
+        match expr {
+            Expr::Grouping(grouping) => self.evaluate_tail(&grouping.expression),
+            Expr::Conditional(conditional) => {
+                if Interpreter::is_truth(walk_expr(self, &conditional.condition)) {
+                    self.evaluate_tail(&conditional.then_branch)
+                } else {
+                    self.evaluate_tail(&conditional.else_branch)
+                }
+            }
+            Expr::Call(call) if !call.optional => match self.get_function(&call.callee) {
+                Some(function) => {
+                    let arguments = self.evaluate_arguments(call);
+                    self.tail_call = Some((function, arguments, call.paren.line));
+                    TokenLiterals::Null
+                }
+                None => walk_expr(self, expr),
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    /// Returns the function an expression refers to,
+    /// if the expression is the name of a function.
+    /// # Arguments
+    /// * `expr` - expression to resolve.
+    pub fn get_function(&self, expr: &Expr) -> Option<Box<FunctionObject>> {
+        let Expr::Variable(variable) = expr else {
+            return None;
+        };
+
+        match self
+            .environment
+            .borrow()
+            .get_from_str(variable.name.lexeme.clone())
+        {
+            Some(SplaxDeclarations::Functions(function)) => Some(function),
+            Some(SplaxDeclarations::Literals(literal)) => match *literal {
+                TokenLiterals::Function(function) => Some(function),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Formats the functions being called, innermost first, consecutive
+    /// calls to the same function are collapsed into one line.
+    pub fn stack_trace(&self) -> String {
+        let mut trace = String::from("Stack trace, most recent call first:");
+        let mut frames = self.call_stack.iter().rev().peekable();
+        while let Some(frame) = frames.next() {
+            let mut repeated = 1;
+            while frames
+                .peek()
+                .is_some_and(|next| next.lexeme == frame.lexeme)
+            {
+                frames.next();
+                repeated += 1;
+            }
+
+            trace.push_str(&format!(
+                "\n    in '{}' declared on line {}",
+                frame.lexeme, frame.line
+            ));
+            if repeated > 1 {
+                trace.push_str(&format!(", repeated {} times", repeated));
+            }
+        }
+        trace
+    }
+
+    /// Calls a value, only functions can be called.
+    /// # Arguments
+    /// * `callee` - value being called.
+    /// * `arguments` - evaluated arguments of the call.
+    /// * `line` - line of the call, for error reporting.
+    pub fn call_value(
+        &mut self,
+        callee: TokenLiterals,
+        arguments: Vec<TokenLiterals>,
+        line: usize,
+    ) -> TokenLiterals {
+        match callee {
+            TokenLiterals::Function(function) => function.call(self, arguments, line),
+            TokenLiterals::Native(function) => function.call(self, arguments, line),
+            callee => {
+                App::runtime_error(
+                    line,
+                    format!("Called a non function type '{}'.", callee.type_name()),
+                );
+                panic!()
+            }
+        }
+    }
+
+    /// Returns the enum declaration an expression refers to,
+    /// if the expression is the name of an enum.
+    /// # Arguments
+    /// * `expr` - expression to resolve.
+    pub fn get_enum(&self, expr: &Expr) -> Option<Box<StmtEnum>> {
+        if let Expr::Variable(variable) = expr {
+            if let Some(SplaxDeclarations::Enums(declaration)) = self
+                .environment
+                .borrow()
+                .get_from_str(variable.name.lexeme.clone())
+            {
+                return Some(declaration);
+            }
+        }
+
+        None
+    }
+
+    /// Constructs a value of an enum's variant, checking
+    /// the variant exists and the number of fields.
+    /// # Arguments
+    /// * `declaration` - declaration of the enum.
+    /// * `name` - name of the variant.
+    /// * `arguments` - values of the variant's fields.
+    pub fn construct_variant(
+        declaration: &StmtEnum,
+        name: &Token,
+        arguments: Vec<TokenLiterals>,
+    ) -> TokenLiterals {
+        let Some(variant) = declaration
+            .variants
+            .iter()
+            .find(|variant| variant.name.lexeme == name.lexeme)
+        else {
+            App::runtime_error(
+                name.line,
+                format!(
+                    "'{}' has no variant '{}'.",
+                    declaration.name.lexeme, name.lexeme
+                ),
+            );
+            panic!()
+        };
+
+        if variant.fields.len() != arguments.len() {
+            App::runtime_error(
+                name.line,
+                format!(
+                    "Expected {} arguments got {}.",
+                    variant.fields.len(),
+                    arguments.len()
+                ),
+            );
+        }
+
+        TokenLiterals::Enum(Box::new(EnumValue {
+            enum_name: declaration.name.lexeme.to_owned(),
+            variant: variant.name.lexeme.to_owned(),
+            fields: arguments,
+        }))
+    }
+
+    /// Binds a value to the names of a pattern.
+    /// # Arguments
+    /// * `pattern` - the pattern to bind.
+    /// * `value` - the value being bound.
+    /// * `define` - if the names are new variables, else they are assigned.
+    /// * `position` - indexes leading to the pattern from the outermost one, like `[1][0]`.
+    pub fn bind_pattern(
+        &mut self,
+        pattern: &BindingPattern,
+        value: TokenLiterals,
+        define: bool,
+        position: &str,
+    ) {
+        match pattern {
+            BindingPattern::Name(name) => self.bind_name(name, value, define),
+            BindingPattern::List(list) => self.bind_list(list, value, define, position),
+        }
+    }
+
+    /// Binds a value to a single name.
+    /// # Arguments
+    /// * `name` - the name to bind.
+    /// * `value` - the value being bound.
+    /// * `define` - if the name is a new variable, else it is assigned.
+    pub fn bind_name(&mut self, name: &Token, value: TokenLiterals, define: bool) {
+        spdlog::debug!("binding '{}' to value : {}", name.lexeme, value);
+        let value = SplaxDeclarations::Literals(Box::new(value));
+        if define {
+            self.environment
+                .borrow_mut()
+                .define(name.lexeme.to_owned(), value);
+        } else {
+            self.environment.borrow_mut().assign(name.to_owned(), value);
+        }
+    }
+
+    /// Binds the elements of a list to the patterns of a list pattern.
+    /// Missing elements take their pattern's default, lists with too few
+    /// or too many elements, or values that aren't lists, are errors.
+    /// # Arguments
+    /// * `pattern` - the list pattern to bind.
+    /// * `value` - the value being bound.
+    /// * `define` - if the names are new variables, else they are assigned.
+    /// * `position` - indexes leading to the pattern from the outermost one, like `[1][0]`.
+    pub fn bind_list(
+        &mut self,
+        pattern: &ListPattern,
+        value: TokenLiterals,
+        define: bool,
+        position: &str,
+    ) {
+        let described = match position {
+            "" => format!("pattern {}", pattern),
+            position => format!("position {} of the pattern", position),
+        };
+
+        let TokenLiterals::List(elements) = &value else {
+            App::runtime_error(
+                pattern.bracket.line,
+                format!(
+                    "Expected a list to destructure at {}, got a '{}'.",
+                    described,
+                    value.type_name()
+                ),
+            );
+            panic!()
+        };
+        // copied so defaults can change the list while it is being destructured.
+        let elements = elements.borrow().clone();
+
+        if pattern.rest.is_none() && elements.len() > pattern.elements.len() {
+            App::runtime_error(
+                pattern.bracket.line,
+                format!(
+                    "Too many values to destructure at {}, it takes {} but the list has {}.",
+                    described,
+                    pattern.elements.len(),
+                    elements.len()
+                ),
+            );
+        }
+
+        for (index, element) in pattern.elements.iter().enumerate() {
+            let element_position = format!("{}[{}]", position, index);
+            let value = match (elements.get(index), &element.default) {
+                (Some(value), _) => value.to_owned(),
+                (None, Some(default)) => walk_expr(self, default),
+                (None, None) => {
+                    App::runtime_error(
+                        pattern.bracket.line,
+                        format!(
+                            "Missing value for '{}' at position {} of the pattern, the list only has {} element{}.",
+                            element.pattern,
+                            element_position,
+                            elements.len(),
+                            if elements.len() == 1 { "" } else { "s" }
+                        ),
+                    );
+                    panic!()
+                }
+            };
+            self.bind_pattern(&element.pattern, value, define, &element_position);
+        }
+
+        if let Some(rest) = &pattern.rest {
+            let remaining = elements
+                .get(pattern.elements.len()..)
+                .unwrap_or_default()
+                .to_vec();
+            self.bind_name(rest, TokenLiterals::new_list(remaining), define);
         }
     }
 }
@@ -1,4 +1,3 @@
-use crate::app::app_main::App;
 use crate::token::token_main::Token;
 use crate::token::token_main::TokenLiterals;
 use crate::token::token_types::TokenType;
@@ -13,16 +12,16 @@ pub struct Lexer {
     pub current: usize,
     /// Current line of the cursor.
     pub line: usize,
-    /// Total length of the input source string.
+    /// Total length of the input source string, in characters.
     pub len: usize,
     /// Final tokens vector.
     pub tokens: Vec<Token>,
-    /// Input source string.
-    pub source_string: String,
     /// Input source string in form of a vector of characters.
     pub source_chars: Vec<char>,
     /// contains all reserved keywords.
     pub reserved_keywords: HashMap<String, TokenType>,
+    /// if any errors were found while scanning.
+    pub has_error: bool,
 }
 
 impl Lexer {
@@ -37,13 +36,14 @@ impl Lexer {
             ')' => self.add_basic_token(TokenType::RightParen),
             '{' => self.add_basic_token(TokenType::LeftBrace),
             '}' => self.add_basic_token(TokenType::RightBrace),
+            '[' => self.add_basic_token(TokenType::LeftBracket),
+            ']' => self.add_basic_token(TokenType::RightBracket),
             ',' => self.add_basic_token(TokenType::Comma),
-            '.' => self.add_basic_token(TokenType::Dot),
-            '-' => self.add_basic_token(TokenType::Minus),
             '+' => self.add_basic_token(TokenType::Plus),
             ';' => self.add_basic_token(TokenType::Semicolon),
-            '*' => self.add_basic_token(TokenType::Star),
             '%' => self.add_basic_token(TokenType::Mod),
+            ':' => self.add_basic_token(TokenType::Colon),
+            '@' => self.add_basic_token(TokenType::At),
 
             // multiple character basic tokens.
             // !
@@ -62,6 +62,9 @@ impl Lexer {
                 if self.match_char('=') {
                     // ==
                     self.add_basic_token(TokenType::EqualEqual);
+                } else if self.match_char('>') {
+                    // =>
+                    self.add_basic_token(TokenType::EqualGreater);
                 } else {
                     // =
                     self.add_basic_token(TokenType::Equal);
@@ -90,6 +93,65 @@ impl Lexer {
                 }
             }
 
+            // .
+            '.' => {
+                if self.look_ahead() == '.' && self.look_ahead_twice() == '.' {
+                    // ...
+                    self.advance();
+                    self.advance();
+                    self.add_basic_token(TokenType::DotDotDot);
+                } else {
+                    // .
+                    self.add_basic_token(TokenType::Dot);
+                }
+            }
+
+            // -
+            '-' => {
+                if self.match_char('>') {
+                    // ->
+                    self.add_basic_token(TokenType::Arrow);
+                } else {
+                    // -
+                    self.add_basic_token(TokenType::Minus);
+                }
+            }
+
+            // *
+            '*' => {
+                if self.match_char('*') {
+                    // **
+                    self.add_basic_token(TokenType::StarStar);
+                } else {
+                    // *
+                    self.add_basic_token(TokenType::Star);
+                }
+            }
+
+            // ?
+            '?' => {
+                if self.match_char('?') {
+                    // ??
+                    self.add_basic_token(TokenType::QuestionQuestion);
+                } else if self.match_char('.') {
+                    // ?.
+                    self.add_basic_token(TokenType::QuestionDot);
+                } else {
+                    // ?
+                    self.add_basic_token(TokenType::Question);
+                }
+            }
+
+            // |
+            '|' => {
+                if self.match_char('>') {
+                    // |>
+                    self.add_basic_token(TokenType::PipeGreater);
+                } else {
+                    self.lexer_report_error("unexpected character : |".to_string());
+                }
+            }
+
             // longer lexemes
             // /
             '/' => {
@@ -139,10 +201,7 @@ impl Lexer {
                 }
                 // it is safe to assume anything else cannot be considered a safe token to parse or interpret.
                 else {
-                    App::error(
-                        self.line,
-                        format!("unexpected character : {}", current_char),
-                    );
+                    self.lexer_report_error(format!("unexpected character : {}", current_char));
                 }
             }
         }
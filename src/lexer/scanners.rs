@@ -1,8 +1,5 @@
 use super::lexer_main::Lexer;
-use crate::{
-    app::app_main::App,
-    token::{token_main::TokenLiterals, token_types::TokenType},
-};
+use crate::token::{token_main::TokenLiterals, token_types::TokenType};
 
 impl Lexer {
     /// Creates a token for strings.
@@ -23,7 +20,7 @@ impl Lexer {
 
         // if reached the end without a "
         if self.is_at_end() {
-            App::error(self.line, "Unterminated string.".to_string());
+            self.lexer_report_error("Unterminated string.".to_string());
             return;
         }
 
@@ -32,7 +29,7 @@ impl Lexer {
         self.advance();
 
         // trim the surrounding quotes.
-        let literal = self.source_string[self.start + 1..self.current - 1].to_string();
+        let literal = self.substring(self.start + 1, self.current - 1);
         self.add_token(TokenType::String, TokenLiterals::String(literal));
     }
 
@@ -62,9 +59,7 @@ impl Lexer {
         }
 
         // getting the literal and parsing it into rust's f64 float.
-        let literal = self.source_string[self.start..self.current]
-            .to_string()
-            .parse::<f64>();
+        let literal = self.substring(self.start, self.current).parse::<f64>();
 
         // checking for result.
         match literal {
@@ -74,7 +69,7 @@ impl Lexer {
             }
             Err(_) => {
                 // printing a error and skipping the token all together.
-                App::error(self.line, "failed to parse into number.".to_string());
+                self.lexer_report_error("failed to parse into number.".to_string());
             }
         }
     }
@@ -88,7 +83,7 @@ impl Lexer {
         }
 
         // lexeme string of the identifier.
-        let lexeme = self.source_string[self.start..self.current].to_string();
+        let lexeme = self.substring(self.start, self.current);
         spdlog::trace!("checking for already existing keywords for : {}", lexeme);
 
         // check if the identifier is a reserved keyword.
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use super::lexer_main::Lexer;
+use crate::app::app_main::App;
 use crate::token::{token_main::Token, token_main::TokenLiterals, token_types::TokenType};
 
 impl Lexer {
@@ -9,15 +10,16 @@ impl Lexer {
     /// * `source_string` - input source string.
     pub fn new(source_string: String, reserved_keywords: HashMap<String, TokenType>) -> Self {
         spdlog::trace!("creating lexer.");
+        let source_chars: Vec<char> = source_string.chars().collect();
         Self {
             start: 0,
             current: 0,
             line: 1,
             tokens: vec![],
-            len: source_string.len(),
-            source_string: source_string.clone(),
-            source_chars: source_string.chars().collect(),
+            len: source_chars.len(),
+            source_chars,
             reserved_keywords,
+            has_error: false,
         }
     }
 
@@ -69,13 +71,30 @@ impl Lexer {
         self.add_token(token_type, TokenLiterals::Null);
     }
 
+    /// lexer error helper, wrapper for App's error.
+    /// # Arguments
+    /// * `message` - The error message to show.
+    pub fn lexer_report_error(&mut self, message: String) {
+        self.has_error = true;
+        App::error(self.line, message);
+    }
+
+    /// Returns the source between two character positions,
+    /// positions count characters so they can't split a multi byte character.
+    /// # Arguments
+    /// * `start` - position of the first character.
+    /// * `end` - position after the last character.
+    pub fn substring(&self, start: usize, end: usize) -> String {
+        self.source_chars[start..end].iter().collect()
+    }
+
     /// Adds a token to the tokens list.
     /// # Arguments
     /// * `token_type` - Type of the token to add.
     /// * `token_literal` - Token literal
     pub fn add_token(&mut self, token_type: TokenType, token_literal: TokenLiterals) {
         spdlog::trace!("adding token : ");
-        let lexeme = self.source_string[self.start..self.current].to_string();
+        let lexeme = self.substring(self.start, self.current);
         self.tokens
             .push(Token::new(token_type, lexeme, token_literal, self.line));
     }
@@ -2,6 +2,7 @@ mod cli;
 
 mod app {
     pub mod app_main;
+    mod check;
     mod compile;
     mod compile_file;
     mod docs;
@@ -33,12 +34,30 @@ mod parser {
     mod utils;
 }
 
+mod checker {
+    pub mod checker_main;
+    mod impl_expr_visitor;
+    mod impl_stmt_visitor;
+    pub mod types;
+    mod utils;
+}
+
 mod interpreter {
     pub mod environment;
+    pub mod error;
+    pub mod format;
+    pub mod fs;
     pub mod globals;
     pub mod impl_expr_visitor;
     pub mod impl_stmt_visitor;
     pub mod interpreter_main;
+    pub mod json;
+    pub mod math;
+    pub mod methods;
+    pub mod natives;
+    pub mod patterns;
+    pub mod process;
+    pub mod strings;
     pub mod utils;
 }
 
@@ -51,6 +70,15 @@ fn main() {
     // setup custom panic!() message.
     app::app_main::App::setup_custom_panic();
 
-    // running.
-    app.run();
+    // running on a thread with a larger stack, so deep recursion is stopped
+    // by the call depth limit instead of overflowing the native stack.
+    let runner = std::thread::Builder::new()
+        .stack_size(app::app_main::App::STACK_SIZE)
+        .spawn(move || app.run())
+        .expect("failed to spawn the interpreter thread");
+
+    // the panic hook has already reported the error, just exiting as a panic would.
+    if let Err(error) = runner.join() {
+        std::panic::resume_unwind(error);
+    }
 }
@@ -3,10 +3,15 @@ use crate::{
     app::app_main::App,
     ast::{
         expr_ast::{
-            Expr, ExprAssign, ExprBinary, ExprCall, ExprGrouping, ExprLiteral, ExprLogical,
-            ExprUnary, ExprVariable,
+            Expr, ExprAssign, ExprBinary, ExprCall, ExprConditional, ExprDestructure, ExprGet,
+            ExprGrouping, ExprIndex, ExprList, ExprLiteral, ExprLogical, ExprOptionalChain,
+            ExprSpread, ExprUnary, ExprVariable,
+        },
+        stmt_ast::{
+            BindingPattern, EnumVariant, ListPattern, MatchArm, MatchPattern, PatternElement, Stmt,
+            StmtAssert, StmtBlock, StmtBreak, StmtContinue, StmtDefer, StmtEnum, StmtExpr,
+            StmtFunc, StmtIf, StmtLet, StmtMatch, StmtPrint, StmtReturn, StmtTry, StmtWhile,
         },
-        stmt_ast::{Stmt, StmtBlock, StmtExpr, StmtFunc, StmtIf, StmtLet, StmtPrint, StmtWhile},
     },
     token::{
         self,
@@ -24,6 +29,12 @@ pub struct Parser {
     pub current: usize,
     // stores if there were any parsing error.
     pub has_error: bool,
+    // how many function bodies deep the parser currently is.
+    pub function_depth: usize,
+    // labels of the loops the parser is currently inside, `None` for unlabelled loops.
+    pub loop_labels: Vec<Option<Token>>,
+    // if the parser is inside a deferred statement, outside of any function in it.
+    pub in_defer: bool,
 }
 
 impl Parser {
@@ -58,10 +69,142 @@ impl Parser {
         if self.match_token(vec![TokenType::Let]) {
             return self.let_declaration();
         }
+        if self.match_token(vec![TokenType::Enum]) {
+            return self.enum_declaration();
+        }
+        if self.check(&TokenType::At) {
+            return self.decorated_declaration();
+        }
 
         self.statement()
     }
 
+    /// parses decorators, `@expression`, and the function declaration they decorate.
+    pub fn decorated_declaration(&mut self) -> Result<Stmt, ParserError> {
+        spdlog::debug!("parsing a decorated declaration.");
+        let mut decorators = vec![];
+        while self.match_token(vec![TokenType::At]) {
+            decorators.push(self.call()?);
+        }
+
+        if !self.match_token(vec![TokenType::Fn]) {
+            return Err(ParserError::new(
+                self.peek(),
+                "Expected function declaration after decorators.".to_string(),
+            ));
+        }
+
+        let mut declaration = self.fn_declaration()?;
+        if let Stmt::Function(function) = &mut declaration {
+            function.decorators = decorators;
+        }
+
+        Ok(declaration)
+    }
+
+    /// parses enum type of declarations.
+    pub fn enum_declaration(&mut self) -> Result<Stmt, ParserError> {
+        spdlog::debug!("parsing an enum declaration.");
+        let name = match self.consume(TokenType::Identifier, "Expected enum name.".to_string()) {
+            Some(name) => name,
+            None => {
+                return Err(ParserError::new(
+                    &self.tokens[self.current],
+                    "Expected enum name.".to_string(),
+                ))
+            }
+        }
+        .clone();
+
+        self.consume(
+            TokenType::LeftBrace,
+            "Expected '{' after enum name.".to_string(),
+        );
+
+        let mut variants: Vec<EnumVariant> = vec![];
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let variant_name =
+                match self.consume(TokenType::Identifier, "Expected variant name.".to_string()) {
+                    Some(variant_name) => variant_name,
+                    None => {
+                        return Err(ParserError::new(
+                            &self.tokens[self.current],
+                            "Expected variant name.".to_string(),
+                        ))
+                    }
+                }
+                .clone();
+
+            if variants
+                .iter()
+                .any(|variant| variant.name.lexeme == variant_name.lexeme)
+            {
+                self.has_error = true;
+                self.parser_report_error(
+                    &variant_name,
+                    format!("Duplicate variant '{}' in enum.", variant_name.lexeme),
+                );
+            }
+
+            // optional fields of the variant.
+            let mut fields = vec![];
+            if self.match_token(vec![TokenType::LeftParen]) {
+                fields = self.identifier_list("Expected field name.")?;
+                self.consume(
+                    TokenType::RightParen,
+                    "Expected ')' after variant fields.".to_string(),
+                );
+            }
+
+            variants.push(EnumVariant {
+                name: variant_name,
+                fields,
+            });
+
+            // variants are separated by commas.
+            if !self.match_token(vec![TokenType::Comma]) {
+                break;
+            }
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            "Expected '}' after enum variants.".to_string(),
+        );
+
+        Ok(Stmt::Enum(Box::new(StmtEnum { name, variants })))
+    }
+
+    /// parses a comma separated, possibly empty, list of identifiers
+    /// ending before a ')'.
+    /// # Arguments
+    /// * `message` - error message in case an identifier is missing.
+    pub fn identifier_list(&mut self, message: &str) -> Result<Vec<Token>, ParserError> {
+        let mut identifiers = vec![];
+        if self.check(&TokenType::RightParen) {
+            return Ok(identifiers);
+        }
+
+        // rust's way of doing do-while loop.
+        loop {
+            match self.consume(TokenType::Identifier, message.to_string()) {
+                Some(identifier) => identifiers.push(identifier.to_owned()),
+                None => {
+                    return Err(ParserError::new(
+                        &self.tokens[self.current],
+                        message.to_string(),
+                    ))
+                }
+            }
+
+            if !self.match_token(vec![TokenType::Comma]) {
+                break;
+            }
+        }
+
+        Ok(identifiers)
+    }
+
     /// parses fn type of declarations.
     pub fn fn_declaration(&mut self) -> Result<Stmt, ParserError> {
         let name = match self.consume(TokenType::Identifier, "Expected function name.".to_string())
@@ -82,6 +225,8 @@ impl Parser {
         );
 
         let mut parameters: Vec<Token> = vec![];
+        let mut param_types: Vec<Option<Token>> = vec![];
+        let mut rest_param = None;
         // if there are parameters.
         if !self.check(&TokenType::RightParen) {
             // rust's way of doing do-while loop.
@@ -94,6 +239,24 @@ impl Parser {
                     ));
                 };
 
+                // rest parameter, has to be the last one.
+                if self.match_token(vec![TokenType::DotDotDot]) {
+                    if let Some(param) = self.consume(
+                        TokenType::Identifier,
+                        "Expected rest parameter name after '...'.".to_string(),
+                    ) {
+                        rest_param = Some(param.to_owned());
+                    };
+
+                    if self.check(&TokenType::Comma) {
+                        return Err(ParserError::new(
+                            self.peek(),
+                            "Rest parameter must be the last parameter.".to_string(),
+                        ));
+                    }
+                    break;
+                }
+
                 if let Some(param) = self.consume(
                     TokenType::Identifier,
                     "Expected parameter name. ".to_string(),
@@ -101,6 +264,9 @@ impl Parser {
                     parameters.push(param.to_owned());
                 };
 
+                // optional type annotation of the parameter.
+                param_types.push(self.type_annotation()?);
+
                 if !self.match_token(vec![TokenType::Comma]) {
                     break;
                 }
@@ -113,13 +279,26 @@ impl Parser {
             "Expected ')' after parameters.".to_string(),
         );
 
+        // optional return type annotation.
+        let mut return_type = None;
+        if self.match_token(vec![TokenType::Arrow]) {
+            return_type = Some(self.type_name()?);
+        }
+
         // now parsing function body
         self.consume(
             TokenType::LeftBrace,
             "Expected '{' after function signature.".to_string(),
         );
 
+        // loops outside of the function can't be broken out of from inside it.
+        let enclosing_loops = std::mem::take(&mut self.loop_labels);
+        let enclosing_defer = std::mem::replace(&mut self.in_defer, false);
+        self.function_depth += 1;
         let body = self.block();
+        self.function_depth -= 1;
+        self.loop_labels = enclosing_loops;
+        self.in_defer = enclosing_defer;
 
         Ok(Stmt::Function(Box::new(StmtFunc {
             name: name.to_owned(),
@@ -127,25 +306,27 @@ impl Parser {
                 block_statements: body,
             },
             params: parameters,
+            param_types,
+            return_type,
+            rest_param,
+            decorators: vec![],
         })))
     }
 
     /// Parses let type of
     pub fn let_declaration(&mut self) -> Result<Stmt, ParserError> {
         spdlog::debug!("parsing a Let declaration.");
-        let name = match self.consume(
-            TokenType::Identifier,
-            "Expectecd variable name.".to_string(),
-        ) {
-            Some(name) => name,
-            None => {
-                return Err(ParserError::new(
-                    &self.tokens[self.current],
-                    "Expected variable name.".to_string(),
-                ))
-            }
+        let pattern = self.binding_pattern()?;
+
+        // optional type annotation of the binding, patterns are always lists.
+        let type_annotation = self.type_annotation()?;
+        if let (BindingPattern::List(list), Some(annotation)) = (&pattern, &type_annotation) {
+            self.has_error = true;
+            self.parser_report_error(
+                annotation,
+                format!("Can't annotate the destructuring pattern {}.", list),
+            );
         }
-        .to_owned();
 
         if self.match_token(vec![TokenType::Equal]) {
             if let Ok(initialiser) = self.expression() {
@@ -153,9 +334,20 @@ impl Parser {
                     TokenType::Semicolon,
                     "Expected ';' after value.".to_string(),
                 );
-                return Ok(Stmt::Let(Box::new(StmtLet { name, initialiser })));
+                return Ok(Stmt::Let(Box::new(StmtLet {
+                    pattern,
+                    type_annotation,
+                    initialiser,
+                })));
             }
         } else {
+            if let BindingPattern::List(list) = &pattern {
+                self.has_error = true;
+                self.parser_report_error(
+                    &list.bracket,
+                    "Destructuring declarations need a value.".to_string(),
+                );
+            }
             self.consume(
                 TokenType::Semicolon,
                 "Expected ';' after value.".to_string(),
@@ -163,7 +355,11 @@ impl Parser {
             let initialiser = Expr::Literal(Box::new(ExprLiteral {
                 value: TokenLiterals::Null,
             }));
-            return Ok(Stmt::Let(Box::new(StmtLet { name, initialiser })));
+            return Ok(Stmt::Let(Box::new(StmtLet {
+                pattern,
+                type_annotation,
+                initialiser,
+            })));
         }
 
         Err(ParserError::new(
@@ -172,6 +368,142 @@ impl Parser {
         ))
     }
 
+    /// Parses the name or list pattern a let declaration binds.
+    pub fn binding_pattern(&mut self) -> Result<BindingPattern, ParserError> {
+        if self.match_token(vec![TokenType::LeftBracket]) {
+            return Ok(BindingPattern::List(Box::new(self.list_pattern()?)));
+        }
+
+        match self.consume(
+            TokenType::Identifier,
+            "Expectecd variable name.".to_string(),
+        ) {
+            Some(name) => Ok(BindingPattern::Name(name.to_owned())),
+            None => Err(ParserError::new(
+                &self.tokens[self.current],
+                "Expected variable name.".to_string(),
+            )),
+        }
+    }
+
+    /// Parses a list pattern after its '[', `[a, [b, c], d = 1, ...rest]`.
+    pub fn list_pattern(&mut self) -> Result<ListPattern, ParserError> {
+        let bracket = self.previous().clone();
+        let mut elements = vec![];
+        let mut rest = None;
+
+        if !self.check(&TokenType::RightBracket) {
+            loop {
+                // the rest binding has to be the last element.
+                if self.match_token(vec![TokenType::DotDotDot]) {
+                    rest = self
+                        .consume(
+                            TokenType::Identifier,
+                            "Expected a name after '...'.".to_string(),
+                        )
+                        .cloned();
+                    break;
+                }
+
+                let pattern = self.binding_pattern()?;
+                let mut default = None;
+                if self.match_token(vec![TokenType::Equal]) {
+                    default = Some(self.expression()?);
+                }
+                elements.push(PatternElement { pattern, default });
+
+                if !self.match_token(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        if self
+            .consume(
+                TokenType::RightBracket,
+                "Expected ']' after pattern, a rest binding has to be last.".to_string(),
+            )
+            .is_none()
+        {
+            return Err(ParserError::new(
+                self.peek(),
+                "Failed parsing list pattern.".to_string(),
+            ));
+        }
+
+        Ok(ListPattern {
+            bracket,
+            elements,
+            rest,
+        })
+    }
+
+    /// Turns the list literal on the left of a `=` into a pattern, for
+    /// destructuring assignments like `[a, b] = [b, a]`. Elements can be
+    /// names, names with defaults, nested lists and a final `...rest`.
+    /// # Arguments
+    /// * `list` - the list literal.
+    /// * `equals` - the '=' token, errors of the pattern are reported on its line.
+    pub fn assignment_pattern(list: &ExprList, equals: &Token) -> Option<ListPattern> {
+        let mut elements = vec![];
+        let mut rest = None;
+
+        for (index, element) in list.elements.iter().enumerate() {
+            let (pattern, default) = match element {
+                Expr::Variable(variable) => (BindingPattern::Name(variable.name.to_owned()), None),
+                Expr::Assignment(assignment) => (
+                    BindingPattern::Name(assignment.name.to_owned()),
+                    Some(assignment.value.to_owned()),
+                ),
+                Expr::List(nested) => (
+                    BindingPattern::List(Box::new(Parser::assignment_pattern(nested, equals)?)),
+                    None,
+                ),
+                Expr::Destructure(nested) => (
+                    BindingPattern::List(Box::new(nested.pattern.to_owned())),
+                    Some(nested.value.to_owned()),
+                ),
+                Expr::Spread(spread) if index + 1 == list.elements.len() => {
+                    if let Expr::Variable(variable) = &spread.expression {
+                        rest = Some(variable.name.to_owned());
+                        continue;
+                    }
+                    return None;
+                }
+                _ => return None,
+            };
+            elements.push(PatternElement { pattern, default });
+        }
+
+        Some(ListPattern {
+            bracket: equals.to_owned(),
+            elements,
+            rest,
+        })
+    }
+
+    /// Parses an optional `: type` annotation, returns the type's name token if present.
+    pub fn type_annotation(&mut self) -> Result<Option<Token>, ParserError> {
+        if self.match_token(vec![TokenType::Colon]) {
+            return Ok(Some(self.type_name()?));
+        }
+
+        Ok(None)
+    }
+
+    /// Parses name of a type in annotations, `null` is a keyword so its
+    /// accepted alongside identifiers.
+    pub fn type_name(&mut self) -> Result<Token, ParserError> {
+        if self.match_token(vec![TokenType::Identifier, TokenType::Null]) {
+            return Ok(self.previous().clone());
+        }
+
+        let peek = self.peek().clone();
+        self.has_error = true;
+        self.parser_report_error(&peek, "Expected type name.".to_string());
+        Err(ParserError::new(&peek, "Expected type name.".to_string()))
+    }
+
     /// Parses statement if not a declaration.
     pub fn statement(&mut self) -> Result<Stmt, ParserError> {
         spdlog::debug!("parsing a statement.");
@@ -181,6 +513,36 @@ impl Parser {
             return self.print_statement();
         }
 
+        // while return indentifier is found.
+        if self.match_token(vec![TokenType::Return]) {
+            return self.return_statement();
+        }
+
+        // while break or continue indentifier is found.
+        if self.match_token(vec![TokenType::Break, TokenType::Continue]) {
+            return self.loop_control_statement();
+        }
+
+        // labelled loops, `label: while (...)`.
+        if self.check(&TokenType::Identifier) && self.check_next(&TokenType::Colon) {
+            return self.labelled_statement();
+        }
+
+        // while defer indentifier is found.
+        if self.match_token(vec![TokenType::Defer]) {
+            return self.defer_statement();
+        }
+
+        // while assert indentifier is found.
+        if self.match_token(vec![TokenType::Assert]) {
+            return self.assert_statement();
+        }
+
+        // while try indentifier is found.
+        if self.match_token(vec![TokenType::Try]) {
+            return self.try_statement();
+        }
+
         // while left brace is found.
         if self.match_token(vec![TokenType::LeftBrace]) {
             return Ok(Stmt::Block(Box::new(StmtBlock {
@@ -191,7 +553,7 @@ impl Parser {
         // for loops
 
         if self.match_token(vec![TokenType::For]) {
-            return self.for_statement();
+            return self.for_statement(None);
         }
 
         // while if indentifier is found.
@@ -201,14 +563,111 @@ impl Parser {
 
         // while while indentifier is found.
         if self.match_token(vec![TokenType::While]) {
-            return self.while_statement();
+            return self.while_statement(None);
+        }
+
+        // while do indentifier is found.
+        if self.match_token(vec![TokenType::Do]) {
+            return self.do_while_statement(None);
+        }
+
+        // while match indentifier is found.
+        if self.match_token(vec![TokenType::Match]) {
+            return self.match_statement();
         }
 
         self.expression_statement()
     }
 
+    /// parses a loop with a label before it, `label: while (...)`.
+    /// Labels have to be unique among the loops they are nested in.
+    pub fn labelled_statement(&mut self) -> Result<Stmt, ParserError> {
+        let label = self.advance().clone();
+        // consuming ':'
+        self.advance();
+
+        if self
+            .loop_labels
+            .iter()
+            .flatten()
+            .any(|enclosing| enclosing.lexeme == label.lexeme)
+        {
+            self.has_error = true;
+            self.parser_report_error(
+                &label,
+                format!("Duplicate label '{}' in nested loops.", label.lexeme),
+            );
+        }
+
+        if self.match_token(vec![TokenType::For]) {
+            return self.for_statement(Some(label));
+        }
+        if self.match_token(vec![TokenType::While]) {
+            return self.while_statement(Some(label));
+        }
+        if self.match_token(vec![TokenType::Do]) {
+            return self.do_while_statement(Some(label));
+        }
+
+        Err(ParserError::new(
+            self.peek(),
+            "Expected a loop after label.".to_string(),
+        ))
+    }
+
+    /// parses the body of a loop, keeping track of the loop's label
+    /// so break and continue statements inside it can be checked.
+    /// # Arguments
+    /// * `label` - optional label of the loop.
+    pub fn loop_body(&mut self, label: &Option<Token>) -> Result<Stmt, ParserError> {
+        self.loop_labels.push(label.clone());
+        let body = self.statement();
+        self.loop_labels.pop();
+        body
+    }
+
+    /// parses break and continue statements, with optional labels.
+    pub fn loop_control_statement(&mut self) -> Result<Stmt, ParserError> {
+        let keyword = self.previous().clone();
+
+        let mut label = None;
+        if self.match_token(vec![TokenType::Identifier]) {
+            label = Some(self.previous().clone());
+        }
+
+        if self.loop_labels.is_empty() {
+            self.has_error = true;
+            self.parser_report_error(
+                &keyword,
+                format!("Can't use '{}' outside of a loop.", keyword.lexeme),
+            );
+        } else if let Some(label) = &label {
+            if !self
+                .loop_labels
+                .iter()
+                .flatten()
+                .any(|enclosing| enclosing.lexeme == label.lexeme)
+            {
+                self.has_error = true;
+                self.parser_report_error(label, format!("Unknown label '{}'.", label.lexeme));
+            }
+        }
+
+        self.consume(
+            TokenType::Semicolon,
+            format!("Expected ';' after '{}'.", keyword.lexeme),
+        );
+
+        if keyword.token_type == TokenType::Break {
+            return Ok(Stmt::Break(Box::new(StmtBreak { keyword, label })));
+        }
+        Ok(Stmt::Continue(Box::new(StmtContinue { keyword, label })))
+    }
+
     /// desugars for loop
-    pub fn for_statement(&mut self) -> Result<Stmt, ParserError> {
+    /// # Arguments
+    /// * `label` - optional label of the loop.
+    pub fn for_statement(&mut self, label: Option<Token>) -> Result<Stmt, ParserError> {
         spdlog::debug!("desugaring a for loop stmt.");
 
         // starting left paren
@@ -259,18 +718,7 @@ impl Parser {
         );
 
         // creating new body
-        if let Ok(mut body) = self.statement() {
-            // if there is a increment.
-
-            if let Ok(increment) = increment {
-                body = Stmt::Block(Box::new(StmtBlock {
-                    block_statements: vec![
-                        body,
-                        Stmt::Expr(Box::new(StmtExpr { expr: increment })),
-                    ],
-                }));
-            }
-
+        if let Ok(mut body) = self.loop_body(&label) {
             // if there are no condition, we default to true.
             if condition.is_err() {
                 condition = Ok(Expr::Literal(Box::new(ExprLiteral {
@@ -284,6 +732,10 @@ impl Parser {
                 // its going to be non None here.
                 condition: condition?,
                 body,
+                // increment runs after every iteration, even ones cut short by continue.
+                increment: increment.ok(),
+                label,
+                do_while: false,
             }));
 
             // if there is a initialiser, we add it before the while loop.
@@ -303,38 +755,72 @@ impl Parser {
     }
 
     /// parses while type of statement.
-    pub fn while_statement(&mut self) -> Result<Stmt, ParserError> {
+    /// # Arguments
+    /// * `label` - optional label of the loop.
+    pub fn while_statement(&mut self, label: Option<Token>) -> Result<Stmt, ParserError> {
         self.consume(
             TokenType::LeftParen,
             "Expected '(' after 'while'".to_string(),
         );
 
-        let condition = match self.expression() {
-            Ok(condition) => condition,
-            Err(e) => return Err(e),
-        };
+        let condition = self.expression()?;
 
         self.consume(
             TokenType::RightParen,
             "Expected ')' after condition".to_string(),
         );
 
-        let body = match self.statement() {
-            Ok(body) => body,
-            Err(e) => return Err(e),
-        };
+        let body = self.loop_body(&label)?;
+
+        Ok(Stmt::While(Box::new(StmtWhile {
+            condition,
+            body,
+            increment: None,
+            label,
+            do_while: false,
+        })))
+    }
+
+    /// parses do while type of statement, body runs before the condition is checked.
+    /// # Arguments
+    /// * `label` - optional label of the loop.
+    pub fn do_while_statement(&mut self, label: Option<Token>) -> Result<Stmt, ParserError> {
+        let body = self.loop_body(&label)?;
+
+        self.consume(
+            TokenType::While,
+            "Expected 'while' after do body".to_string(),
+        );
+        self.consume(
+            TokenType::LeftParen,
+            "Expected '(' after 'while'".to_string(),
+        );
+
+        let condition = self.expression()?;
+
+        self.consume(
+            TokenType::RightParen,
+            "Expected ')' after condition".to_string(),
+        );
+        self.consume(
+            TokenType::Semicolon,
+            "Expected ';' after do while loop".to_string(),
+        );
 
-        Ok(Stmt::While(Box::new(StmtWhile { condition, body })))
+        Ok(Stmt::While(Box::new(StmtWhile {
+            condition,
+            body,
+            increment: None,
+            label,
+            do_while: true,
+        })))
     }
 
     /// parses if type of statement
     pub fn if_statement(&mut self) -> Result<Stmt, ParserError> {
         self.consume(TokenType::LeftParen, "expected '(' after 'if'".to_string());
         // the condition inside 'if ()'
-        let condition = match self.expression() {
-            Ok(condition) => condition,
-            Err(e) => return Err(e),
-        };
+        let condition = self.expression()?;
 
         self.consume(
             TokenType::RightParen,
@@ -343,10 +829,7 @@ impl Parser {
 
         // block inside if condition tree.
 
-        let then_branch = match self.statement() {
-            Ok(statement) => statement,
-            Err(e) => return Err(e),
-        };
+        let then_branch = self.statement()?;
 
         // optional else branch.
         let mut else_branch: Result<Stmt, ParserError> = Err(ParserError::new(
@@ -365,6 +848,103 @@ impl Parser {
         })))
     }
 
+    /// parses match type of statement.
+    pub fn match_statement(&mut self) -> Result<Stmt, ParserError> {
+        spdlog::debug!("parsing a match statement.");
+        let keyword = self.previous().clone();
+
+        self.consume(
+            TokenType::LeftParen,
+            "Expected '(' after 'match'.".to_string(),
+        );
+        let subject = self.expression()?;
+        self.consume(
+            TokenType::RightParen,
+            "Expected ')' after match subject.".to_string(),
+        );
+
+        self.consume(
+            TokenType::LeftBrace,
+            "Expected '{' before match arms.".to_string(),
+        );
+
+        let mut arms = vec![];
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let pattern = self.match_pattern()?;
+            self.consume(
+                TokenType::EqualGreater,
+                "Expected '=>' after match pattern.".to_string(),
+            );
+            let body = self.statement()?;
+            arms.push(MatchArm { pattern, body });
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            "Expected '}' after match arms.".to_string(),
+        );
+
+        Ok(Stmt::Match(Box::new(StmtMatch {
+            keyword,
+            subject,
+            arms,
+        })))
+    }
+
+    /// parses pattern of a match arm, either `_` or `Enum.Variant(bindings)`.
+    pub fn match_pattern(&mut self) -> Result<MatchPattern, ParserError> {
+        let enum_name =
+            match self.consume(TokenType::Identifier, "Expected pattern.".to_string()) {
+                Some(enum_name) => enum_name,
+                None => {
+                    return Err(ParserError::new(
+                        &self.tokens[self.current],
+                        "Expected pattern.".to_string(),
+                    ))
+                }
+            }
+            .clone();
+
+        // wildcard pattern.
+        if enum_name.lexeme == "_" {
+            return Ok(MatchPattern::Wildcard);
+        }
+
+        self.consume(
+            TokenType::Dot,
+            "Expected '.' after enum name in pattern.".to_string(),
+        );
+        let variant = match self.consume(
+            TokenType::Identifier,
+            "Expected variant name in pattern.".to_string(),
+        ) {
+            Some(variant) => variant,
+            None => {
+                return Err(ParserError::new(
+                    &self.tokens[self.current],
+                    "Expected variant name in pattern.".to_string(),
+                ))
+            }
+        }
+        .clone();
+
+        // optional bindings for the variant's fields.
+        let mut bindings = vec![];
+        if self.match_token(vec![TokenType::LeftParen]) {
+            bindings = self.identifier_list("Expected binding name.")?;
+            self.consume(
+                TokenType::RightParen,
+                "Expected ')' after pattern bindings.".to_string(),
+            );
+        }
+
+        Ok(MatchPattern::Variant {
+            enum_name,
+            variant,
+            bindings,
+        })
+    }
+
     /// parses print type of statement
     pub fn print_statement(&mut self) -> Result<Stmt, ParserError> {
         spdlog::debug!("parsing a Let declaration.");
@@ -383,6 +963,133 @@ impl Parser {
         ))
     }
 
+    /// parses return type of statement.
+    pub fn return_statement(&mut self) -> Result<Stmt, ParserError> {
+        spdlog::debug!("parsing a return statement.");
+        let keyword = self.previous().clone();
+
+        if self.function_depth == 0 {
+            self.has_error = true;
+            self.parser_report_error(&keyword, "Can't return from top-level code.".to_string());
+        } else if self.in_defer {
+            self.has_error = true;
+            self.parser_report_error(
+                &keyword,
+                "Can't return from a deferred statement.".to_string(),
+            );
+        }
+
+        // return value defaults to null.
+        let mut value = Expr::Literal(Box::new(ExprLiteral {
+            value: TokenLiterals::Null,
+        }));
+        if !self.check(&TokenType::Semicolon) {
+            value = self.expression()?;
+        }
+
+        self.consume(
+            TokenType::Semicolon,
+            "Expected ';' after return value.".to_string(),
+        );
+
+        Ok(Stmt::Return(Box::new(StmtReturn { keyword, value })))
+    }
+
+    /// parses defer type of statement.
+    pub fn defer_statement(&mut self) -> Result<Stmt, ParserError> {
+        spdlog::debug!("parsing a defer statement.");
+        let keyword = self.previous().clone();
+
+        // deferred statements run after their block has exited,
+        // so they can't return or break out of it.
+        let enclosing_loops = std::mem::take(&mut self.loop_labels);
+        let enclosing_defer = std::mem::replace(&mut self.in_defer, true);
+        let body = self.statement();
+        self.loop_labels = enclosing_loops;
+        self.in_defer = enclosing_defer;
+        let body = body?;
+
+        Ok(Stmt::Defer(Box::new(StmtDefer { keyword, body })))
+    }
+
+    /// parses try type of statement, `try { ... } catch (error) { ... }`.
+    pub fn try_statement(&mut self) -> Result<Stmt, ParserError> {
+        spdlog::debug!("parsing a try statement.");
+        let keyword = self.previous().clone();
+
+        self.consume(
+            TokenType::LeftBrace,
+            "Expected '{' after 'try'.".to_string(),
+        );
+        let body = StmtBlock {
+            block_statements: self.block(),
+        };
+
+        self.consume(
+            TokenType::Catch,
+            "Expected 'catch' after try block.".to_string(),
+        );
+        self.consume(
+            TokenType::LeftParen,
+            "Expected '(' after 'catch'.".to_string(),
+        );
+        let name = match self.consume(
+            TokenType::Identifier,
+            "Expected error variable name.".to_string(),
+        ) {
+            Some(name) => name.clone(),
+            None => {
+                return Err(ParserError::new(
+                    self.peek(),
+                    "Failed parsing catch clause.".to_string(),
+                ))
+            }
+        };
+        self.consume(
+            TokenType::RightParen,
+            "Expected ')' after error variable name.".to_string(),
+        );
+
+        self.consume(
+            TokenType::LeftBrace,
+            "Expected '{' after catch clause.".to_string(),
+        );
+        let handler = StmtBlock {
+            block_statements: self.block(),
+        };
+
+        Ok(Stmt::Try(Box::new(StmtTry {
+            keyword,
+            body,
+            name,
+            handler,
+        })))
+    }
+
+    /// parses assert type of statement.
+    pub fn assert_statement(&mut self) -> Result<Stmt, ParserError> {
+        spdlog::debug!("parsing an assert statement.");
+        let keyword = self.previous().clone();
+        let condition = self.expression()?;
+
+        // optional failure message.
+        let mut message = None;
+        if self.match_token(vec![TokenType::Comma]) {
+            message = Some(self.expression()?);
+        }
+
+        self.consume(
+            TokenType::Semicolon,
+            "Expected ';' after assertion.".to_string(),
+        );
+
+        Ok(Stmt::Assert(Box::new(StmtAssert {
+            keyword,
+            condition,
+            message,
+        })))
+    }
+
     pub fn block(&mut self) -> Vec<Stmt> {
         let mut block_statements = vec![];
         while !self.match_token(vec![TokenType::RightBrace]) && !self.is_at_end() {
@@ -430,13 +1137,11 @@ impl Parser {
     /// Parsing method for assignment expressions
     pub fn assignment(&mut self) -> Result<Expr, ParserError> {
         spdlog::trace!("parsing assignment");
-        let expr = match self.or() {
-            Ok(expr) => expr,
-            Err(e) => return Err(e),
-        };
+        let expr = self.pipeline()?;
 
         // if we find a '='.
         if self.match_token(vec![TokenType::Equal]) {
+            let equals = self.previous().clone();
             if let Ok(value) = self.assignment() {
                 if let Expr::Variable(expr) = expr {
                     // create assignment expression if left token was variable,
@@ -445,29 +1150,174 @@ impl Parser {
                     let name = expr.name;
                     return Ok(Expr::Assignment(Box::new(ExprAssign { name, value })));
                 }
+
+                // destructuring assignments, `[a, b] = [b, a]`.
+                if let Expr::List(list) = &expr {
+                    if let Some(pattern) = Parser::assignment_pattern(list, &equals) {
+                        return Ok(Expr::Destructure(Box::new(ExprDestructure {
+                            pattern,
+                            value,
+                        })));
+                    }
+                }
             }
 
             // we error if found weird assignment expression.
-            App::runtime_error(self.previous().line, "Invalid assignment.".to_string());
-            panic!();
+            self.has_error = true;
+            self.parser_report_error(&equals, "Invalid assignment target.".to_string());
+            return Err(ParserError::new(
+                &equals,
+                "Invalid assignment target.".to_string(),
+            ));
         }
 
         // return the expr itself if didnt found a '='
         Ok(expr)
     }
 
+    /// Parsing method for pipeline expressions ( `value |> f(args)` ).
+    /// Pipelines are desugared into calls with the left value as the first
+    /// argument, so `a |> f(b) |> g` becomes `g(f(a, b))`.
+    pub fn pipeline(&mut self) -> Result<Expr, ParserError> {
+        spdlog::trace!("parsing pipeline");
+        let mut left = self.conditional()?;
+
+        // loop as long as we recieve '|>' tokens.
+        while self.match_token(vec![TokenType::PipeGreater]) {
+            let operator = self.previous().clone();
+            left = match self.call()? {
+                // `value |> f(args)` becomes `f(value, args)`.
+                Expr::Call(mut call) => {
+                    call.arguments.insert(0, left);
+                    Expr::Call(call)
+                }
+                // `value |> a?.f(args)` becomes `a?.f(value, args)`.
+                Expr::OptionalChain(mut chain) if matches!(chain.expression, Expr::Call(_)) => {
+                    if let Expr::Call(call) = &mut chain.expression {
+                        call.arguments.insert(0, left);
+                    }
+                    Expr::OptionalChain(chain)
+                }
+
+                // `value |> f` becomes `f(value)`, whether the right side
+                // can be called is only known at runtime.
+                callee => Expr::Call(Box::new(ExprCall {
+                    callee,
+                    paren: operator,
+                    arguments: vec![left],
+                    optional: false,
+                })),
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// Parsing method for conditional expressions ( `condition ? a : b` ).
+    /// Right associative, so `a ? b : c ? d : e` is `a ? b : (c ? d : e)`.
+    pub fn conditional(&mut self) -> Result<Expr, ParserError> {
+        spdlog::trace!("parsing conditional");
+        let condition = self.coalesce()?;
+
+        // if we find a '?'.
+        if self.match_token(vec![TokenType::Question]) {
+            let then_branch = self.expression()?;
+            self.consume(
+                TokenType::Colon,
+                "Expected ':' after then branch of conditional expression.".to_string(),
+            );
+            let else_branch = self.conditional()?;
+
+            return Ok(Expr::Conditional(Box::new(ExprConditional {
+                condition,
+                then_branch,
+                else_branch,
+            })));
+        }
+
+        Ok(condition)
+    }
+
+    /// Parsing method for if expressions, `if (condition) { a } else { b }`.
+    /// Unlike if statements the else branch is required, since the
+    /// expression always has to produce a value.
+    pub fn if_expression(&mut self) -> Result<Expr, ParserError> {
+        spdlog::trace!("parsing if expression");
+        self.consume(TokenType::LeftParen, "expected '(' after 'if'".to_string());
+        let condition = self.expression()?;
+        self.consume(
+            TokenType::RightParen,
+            "expected ')' after 'condition'".to_string(),
+        );
+
+        let then_branch = self.branch_expression()?;
+
+        self.consume(
+            TokenType::Else,
+            "Expected 'else' branch in if expression.".to_string(),
+        );
+
+        // else if chains are nested if expressions.
+        let else_branch = if self.match_token(vec![TokenType::If]) {
+            self.if_expression()?
+        } else {
+            self.branch_expression()?
+        };
+
+        Ok(Expr::Conditional(Box::new(ExprConditional {
+            condition,
+            then_branch,
+            else_branch,
+        })))
+    }
+
+    /// Parses a branch of an if expression, a single expression inside braces.
+    pub fn branch_expression(&mut self) -> Result<Expr, ParserError> {
+        self.consume(
+            TokenType::LeftBrace,
+            "Expected '{' before if expression branch.".to_string(),
+        );
+        let expr = self.expression()?;
+        self.consume(
+            TokenType::RightBrace,
+            "Expected '}' after if expression branch.".to_string(),
+        );
+
+        Ok(expr)
+    }
+
+    /// Parsing method for null coalescing expressions ( `a ?? b` ).
+    /// Right associative, evaluated like logical expressions.
+    pub fn coalesce(&mut self) -> Result<Expr, ParserError> {
+        spdlog::trace!("parsing coalesce");
+        let left = self.or()?;
+
+        // if we find a '??'.
+        if self.match_token(vec![TokenType::QuestionQuestion]) {
+            let operator = self.previous().clone();
+            let right = self.coalesce()?;
+            return Ok(Expr::Logical(Box::new(ExprLogical {
+                left,
+                operator,
+                right,
+            })));
+        }
+
+        Ok(left)
+    }
+
     // Parsing logical or
     pub fn or(&mut self) -> Result<Expr, ParserError> {
-        if let Ok(left) = self.and() {
+        if let Ok(mut left) = self.and() {
             // recursively loop as long as we recieve OR type tokens.
             while self.match_token(vec![TokenType::Or]) {
                 let operator = self.previous().clone();
                 if let Ok(right) = self.and() {
-                    return Ok(Expr::Logical(Box::new(ExprLogical {
+                    left = Expr::Logical(Box::new(ExprLogical {
                         left,
                         operator,
                         right,
-                    })));
+                    }));
                 }
             }
             return Ok(left);
@@ -480,16 +1330,16 @@ impl Parser {
 
     // parsing logical and
     pub fn and(&mut self) -> Result<Expr, ParserError> {
-        if let Ok(left) = self.equality() {
+        if let Ok(mut left) = self.equality() {
             // recursively loop as long as we recieve AND type tokens.
             while self.match_token(vec![TokenType::And]) {
                 let operator = self.previous().clone();
                 if let Ok(right) = self.equality() {
-                    return Ok(Expr::Logical(Box::new(ExprLogical {
+                    left = Expr::Logical(Box::new(ExprLogical {
                         left,
                         operator,
                         right,
-                    })));
+                    }));
                 }
             }
             return Ok(left);
@@ -504,16 +1354,16 @@ impl Parser {
     /// Terminal type.
     pub fn equality(&mut self) -> Result<Expr, ParserError> {
         spdlog::trace!("parsing equality");
-        if let Ok(left) = self.comparison() {
+        if let Ok(mut left) = self.comparison() {
             // recursively loop as long as we recieve BangEqual or EqualEqual type tokens.
             while self.match_token(vec![TokenType::BangEqual, TokenType::EqualEqual]) {
                 let operator = self.previous().clone();
                 if let Ok(right) = self.comparison() {
-                    return Ok(Expr::Binary(Box::new(ExprBinary {
+                    left = Expr::Binary(Box::new(ExprBinary {
                         left,
                         operator,
                         right,
-                    })));
+                    }));
                 }
             }
             return Ok(left);
@@ -528,7 +1378,7 @@ impl Parser {
     /// Nonterminal type.
     pub fn comparison(&mut self) -> Result<Expr, ParserError> {
         spdlog::trace!("parsing comparison");
-        if let Ok(left) = self.term() {
+        if let Ok(mut left) = self.term() {
             // recursively loop as long as we recieve Greater, GreaterEqual,
             // Less, LessEqual type tokens.
             while self.match_token(vec![
@@ -539,11 +1389,11 @@ impl Parser {
             ]) {
                 let operator = self.previous().clone();
                 if let Ok(right) = self.term() {
-                    return Ok(Expr::Binary(Box::new(ExprBinary {
+                    left = Expr::Binary(Box::new(ExprBinary {
                         left,
                         operator,
                         right,
-                    })));
+                    }));
                 }
             }
 
@@ -560,16 +1410,16 @@ impl Parser {
     /// Nonterminal type.
     pub fn term(&mut self) -> Result<Expr, ParserError> {
         spdlog::trace!("parsing term");
-        if let Ok(left) = self.factor() {
+        if let Ok(mut left) = self.factor() {
             // recursive loop as long as we recieve Minus or Plus type tokens.
             while self.match_token(vec![TokenType::Minus, TokenType::Plus]) {
                 let operator = self.previous().clone();
                 if let Ok(right) = self.factor() {
-                    return Ok(Expr::Binary(Box::new(ExprBinary {
+                    left = Expr::Binary(Box::new(ExprBinary {
                         left,
                         operator,
                         right,
-                    })));
+                    }));
                 }
             }
 
@@ -586,15 +1436,15 @@ impl Parser {
     /// Nonterminal type.
     pub fn factor(&mut self) -> Result<Expr, ParserError> {
         spdlog::trace!("parsing factor");
-        if let Ok(left) = self.unary() {
+        if let Ok(mut left) = self.unary() {
             while self.match_token(vec![TokenType::Slash, TokenType::Star, TokenType::Mod]) {
                 let operator = self.previous().clone();
                 if let Ok(right) = self.unary() {
-                    return Ok(Expr::Binary(Box::new(ExprBinary {
+                    left = Expr::Binary(Box::new(ExprBinary {
                         left,
                         operator,
                         right,
-                    })));
+                    }));
                 }
             }
 
@@ -619,42 +1469,125 @@ impl Parser {
             }
         }
 
-        // the expression might be a call.
-        self.call()
+        // the expression might be an exponent.
+        self.exponent()
+    }
+
+    /// Parsing method for exponent type expressions.
+    /// Nonterminal type.
+    /// Right associative, the right operand is parsed as a unary so that
+    /// `2 ** 3 ** 2` is `2 ** (3 ** 2)` and `2 ** -1` is valid.
+    pub fn exponent(&mut self) -> Result<Expr, ParserError> {
+        spdlog::trace!("parsing exponent");
+        if let Ok(left) = self.call() {
+            if self.match_token(vec![TokenType::StarStar]) {
+                let operator = self.previous().clone();
+                if let Ok(right) = self.unary() {
+                    return Ok(Expr::Binary(Box::new(ExprBinary {
+                        left,
+                        operator,
+                        right,
+                    })));
+                }
+            }
+
+            return Ok(left);
+        }
+
+        Err(ParserError::new(
+            &self.tokens[self.current],
+            "Failed to parse exponent".to_string(),
+        ))
     }
 
     // Parsing function call.
     pub fn call(&mut self) -> Result<Expr, ParserError> {
         let mut expr = self.primary();
+        // if the chain has a `?.`, which can short-circuit all of it.
+        let mut optional_chain = false;
         loop {
             if self.match_token(vec![TokenType::LeftParen]) {
-                expr = self.finish_call(expr);
+                expr = self.finish_call(expr, false);
+            } else if self.match_token(vec![TokenType::LeftBracket]) {
+                let index = self.expression()?;
+                let bracket = match self.consume(
+                    TokenType::RightBracket,
+                    "Expected ']' after index.".to_string(),
+                ) {
+                    Some(bracket) => bracket.to_owned(),
+                    None => {
+                        return Err(ParserError::new(
+                            self.peek(),
+                            "Failed parsing index.".to_string(),
+                        ))
+                    }
+                };
+                expr = Ok(Expr::Index(Box::new(ExprIndex {
+                    object: expr?,
+                    bracket,
+                    index,
+                })));
+            } else if self.match_token(vec![TokenType::Dot]) {
+                expr = self.finish_get(expr, false);
+            } else if self.match_token(vec![TokenType::QuestionDot]) {
+                // optional calls `f?.()` and optional property access `object?.name`.
+                optional_chain = true;
+                if self.match_token(vec![TokenType::LeftParen]) {
+                    expr = self.finish_call(expr, true);
+                } else {
+                    expr = self.finish_get(expr, true);
+                }
             } else {
                 break;
             }
         }
 
+        if optional_chain {
+            return Ok(Expr::OptionalChain(Box::new(ExprOptionalChain {
+                expression: expr?,
+            })));
+        }
         expr
     }
+    // parsing property access after `.` or `?.`.
+    pub fn finish_get(
+        &mut self,
+        object: Result<Expr, ParserError>,
+        optional: bool,
+    ) -> Result<Expr, ParserError> {
+        // keywords are allowed as property names, like `re.match`.
+        let name = match self.consume_property_name() {
+            Some(name) => name,
+            None => {
+                return Err(ParserError::new(
+                    self.peek(),
+                    "Failed parsing property access.".to_string(),
+                ))
+            }
+        };
+        Ok(Expr::Get(Box::new(ExprGet {
+            object: object?,
+            name,
+            optional,
+        })))
+    }
+
     // parsing tailing function calls.
-    pub fn finish_call(&mut self, callee: Result<Expr, ParserError>) -> Result<Expr, ParserError> {
+    pub fn finish_call(
+        &mut self,
+        callee: Result<Expr, ParserError>,
+        optional: bool,
+    ) -> Result<Expr, ParserError> {
         if let Ok(callee) = callee {
             let mut arguments = vec![];
 
             // if we find right paren.
             if !self.check(&TokenType::RightParen) {
                 // rust way of doing do-while loop.
+                // the 255 arguments limit is checked at runtime, after spreads are expanded.
                 loop {
-                    if arguments.len() >= 255 {
-                        let error_token = &self.tokens[self.current].to_owned();
-                        self.parser_report_error(
-                            error_token,
-                            "Can't have more than 255 function arguments.".to_string(),
-                        )
-                    }
-
                     // add arguments.
-                    if let Ok(argument) = self.expression() {
+                    if let Ok(argument) = self.spread_or_expression() {
                         arguments.push(argument);
                     }
                     // if we see a comma we have reached the end of this argument.
@@ -672,6 +1605,7 @@ impl Parser {
                     callee,
                     paren: paren.to_owned(),
                     arguments,
+                    optional,
                 })));
             }
         }
@@ -681,6 +1615,43 @@ impl Parser {
         ))
     }
 
+    /// Parses either a spread `...expression` or an expression,
+    /// used for call arguments and list elements.
+    pub fn spread_or_expression(&mut self) -> Result<Expr, ParserError> {
+        if self.match_token(vec![TokenType::DotDotDot]) {
+            let operator = self.previous().clone();
+            let expression = self.expression()?;
+            return Ok(Expr::Spread(Box::new(ExprSpread {
+                operator,
+                expression,
+            })));
+        }
+
+        self.expression()
+    }
+
+    /// Parsing method for list literals, after the opening '['.
+    pub fn list(&mut self) -> Result<Expr, ParserError> {
+        let mut elements = vec![];
+
+        if !self.check(&TokenType::RightBracket) {
+            // rust way of doing do-while loop.
+            loop {
+                elements.push(self.spread_or_expression()?);
+                if !self.match_token(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        self.consume(
+            TokenType::RightBracket,
+            "Expected ']' after list elements.".to_string(),
+        );
+
+        Ok(Expr::List(Box::new(ExprList { elements })))
+    }
+
     /// Parsing method for primary type expressions.
     /// Terminal type.
     pub fn primary(&mut self) -> Result<Expr, ParserError> {
@@ -713,7 +1684,7 @@ impl Parser {
         if self.match_token(vec![TokenType::String]) {
             spdlog::trace!("matched literal: String");
             return Ok(Expr::Literal(Box::new(ExprLiteral {
-                value: token::token_main::TokenLiterals::String(self.previous().lexeme.to_owned()),
+                value: self.previous().literal.to_owned(),
             })));
         }
 
@@ -736,6 +1707,18 @@ impl Parser {
             })));
         }
 
+        // if expressions, statement level ifs are parsed before reaching here.
+        if self.match_token(vec![TokenType::If]) {
+            spdlog::trace!("matched If, trying to form an if expression.");
+            return self.if_expression();
+        }
+
+        // list literals.
+        if self.match_token(vec![TokenType::LeftBracket]) {
+            spdlog::trace!("matched LeftBracket, trying to form a list.");
+            return self.list();
+        }
+
         // grouping.
         if self.match_token(vec![TokenType::LeftParen]) {
             spdlog::trace!("matched literal: LeftParen, trying to form a grouping.");
@@ -14,6 +14,9 @@ impl Parser {
             tokens,
             current: 0,
             has_error: false,
+            function_depth: 0,
+            loop_labels: vec![],
+            in_defer: false,
         }
     }
 
@@ -91,11 +94,41 @@ impl Parser {
         self.peek().token_type == *token_type
     }
 
+    /// Checks if the token after the current one is of given argument's type.
+    /// # Arguments
+    /// * `token` - The TokenType to compare.
+    pub fn check_next(&self, token_type: &TokenType) -> bool {
+        match self.tokens.get(self.current + 1) {
+            Some(token) => token.token_type == *token_type,
+            None => false,
+        }
+    }
+
     /// Returns the next token without consuming it.
     pub fn peek(&self) -> &Token {
         &self.tokens[self.current]
     }
 
+    /// Consumes the name of a property after a '.', which can also be
+    /// a keyword, like `re.match`. Keywords are the only tokens other
+    /// than identifiers made of letters.
+    pub fn consume_property_name(&mut self) -> Option<Token> {
+        let token = self.peek();
+        let is_word = token.token_type != TokenType::Eof
+            && token
+                .lexeme
+                .starts_with(|character: char| character.is_alphabetic() || character == '_');
+        if is_word && token.token_type != TokenType::Identifier {
+            return Some(self.advance().to_owned());
+        }
+
+        self.consume(
+            TokenType::Identifier,
+            "Expected property name after '.'.".to_string(),
+        )
+        .cloned()
+    }
+
     /// Check and returns if the next token is of EOF.
     pub fn is_at_end(&self) -> bool {
         self.peek().token_type == TokenType::Eof
@@ -122,7 +155,12 @@ impl Parser {
                 | TokenType::If
                 | TokenType::While
                 | TokenType::For
-                | TokenType::Print => {
+                | TokenType::Print
+                | TokenType::Enum
+                | TokenType::Match
+                | TokenType::Assert
+                | TokenType::Do
+                | TokenType::Defer => {
                     spdlog::trace!("found statement starting indentifier, synchronizing.");
                     return;
                 }
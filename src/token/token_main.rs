@@ -1,4 +1,10 @@
 use super::token_types::TokenType;
+use crate::{
+    ast::expr_ast::FunctionObject,
+    interpreter::natives::{NativeFunction, NativeModule},
+};
+use regex::Regex;
+use std::{cell::RefCell, rc::Rc};
 
 /// The Token struct, holds info about a single token.
 #[derive(Debug, Clone)]
@@ -24,6 +30,33 @@ pub enum TokenLiterals {
     Boolean(bool),
     // all other token types.
     Null,
+    // value of an enum variant, only created at runtime.
+    Enum(Box<EnumValue>),
+    // list of values, only created at runtime.
+    // lists are shared between every variable holding them.
+    List(Rc<RefCell<Vec<TokenLiterals>>>),
+    // function values, only created at runtime.
+    Function(Box<FunctionObject>),
+    // functions implemented in rust.
+    Native(Box<NativeFunction>),
+    // namespaces of native functions, like `math`.
+    Module(Rc<NativeModule>),
+    // compiled regular expressions.
+    Pattern(Regex),
+}
+
+/// Address of a list, used to detect lists that contain themselves.
+pub type ListPointer = *const RefCell<Vec<TokenLiterals>>;
+
+/// Runtime value of an enum variant, holds its tag and fields.
+#[derive(Debug, Clone)]
+pub struct EnumValue {
+    // name of the enum this value belongs to.
+    pub enum_name: String,
+    // name of the variant.
+    pub variant: String,
+    // values of the variant's fields.
+    pub fields: Vec<TokenLiterals>,
 }
 
 impl Token {
@@ -51,6 +84,31 @@ impl Token {
     }
 }
 
+impl TokenLiterals {
+    /// Creates a new list literal from values.
+    /// # Arguments
+    /// * `values` - elements of the list.
+    pub fn new_list(values: Vec<TokenLiterals>) -> Self {
+        TokenLiterals::List(Rc::new(RefCell::new(values)))
+    }
+
+    /// Returns the name of the runtime type of the literal,
+    /// used in error messages.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            TokenLiterals::Number(_) => "Number",
+            TokenLiterals::String(_) => "String",
+            TokenLiterals::Boolean(_) => "Boolean",
+            TokenLiterals::Null => "Null",
+            TokenLiterals::Enum(_) => "Enum",
+            TokenLiterals::List(_) => "List",
+            TokenLiterals::Function(_) | TokenLiterals::Native(_) => "Function",
+            TokenLiterals::Module(_) => "Module",
+            TokenLiterals::Pattern(_) => "Pattern",
+        }
+    }
+}
+
 /// Using Debug's implementation as the Display implementation for Token.
 impl std::fmt::Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -58,6 +116,52 @@ impl std::fmt::Display for Token {
     }
 }
 
+impl TokenLiterals {
+    /// Writes the literal, tracking the lists currently being written so that
+    /// a list containing itself prints as `[...]` instead of recursing forever.
+    /// # Arguments
+    /// * `f` - formatter to write into.
+    /// * `parents` - lists enclosing the value being written.
+    fn write_value(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        parents: &mut Vec<ListPointer>,
+    ) -> std::fmt::Result {
+        match self {
+            TokenLiterals::Enum(s) => {
+                write!(f, "{}.{}", s.enum_name, s.variant)?;
+                if !s.fields.is_empty() {
+                    write!(f, "(")?;
+                    for (index, field) in s.fields.iter().enumerate() {
+                        if index > 0 {
+                            write!(f, ", ")?;
+                        }
+                        field.write_value(f, parents)?;
+                    }
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            TokenLiterals::List(s) => {
+                if parents.contains(&Rc::as_ptr(s)) {
+                    return write!(f, "[...]");
+                }
+                parents.push(Rc::as_ptr(s));
+                write!(f, "[")?;
+                for (index, element) in s.borrow().iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    element.write_value(f, parents)?;
+                }
+                parents.pop();
+                write!(f, "]")
+            }
+            _ => write!(f, "{}", self),
+        }
+    }
+}
+
 /// display implementation for token literals.
 impl std::fmt::Display for TokenLiterals {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -66,6 +170,11 @@ impl std::fmt::Display for TokenLiterals {
             TokenLiterals::String(s) => write!(f, "{}", s),
             TokenLiterals::Boolean(s) => write!(f, "{}", s),
             TokenLiterals::Null => write!(f, "null"),
+            TokenLiterals::Enum(_) | TokenLiterals::List(_) => self.write_value(f, &mut Vec::new()),
+            TokenLiterals::Function(s) => write!(f, "<fn '{}'>", s.declaration.name.lexeme),
+            TokenLiterals::Native(s) => write!(f, "<native fn '{}'>", s.name),
+            TokenLiterals::Module(s) => write!(f, "<module '{}'>", s.name),
+            TokenLiterals::Pattern(s) => write!(f, "<pattern '{}'>", s.as_str()),
         }
     }
 }
@@ -2,28 +2,40 @@
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
     // single-character tokens.
-    LeftParen,  // (
-    RightParen, // )
-    LeftBrace,  // {
-    RightBrace, // }
-    Comma,      // ,
-    Dot,        // .
-    Minus,      // -
-    Plus,       // +
-    Semicolon,  // ;
-    Slash,      // /
-    Star,       // *
-    Mod,        // %
+    LeftParen,    // (
+    RightParen,   // )
+    LeftBrace,    // {
+    RightBrace,   // }
+    LeftBracket,  // [
+    RightBracket, // ]
+    Comma,        // ,
+    Dot,          // .
+    Minus,        // -
+    Plus,         // +
+    Semicolon,    // ;
+    Slash,        // /
+    Star,         // *
+    Mod,          // %
+    Question,     // ?
+    Colon,        // :
+    At,           // @
 
     // one or two character tokens.
-    Bang,         // !
-    BangEqual,    // !=
-    Equal,        // =
-    EqualEqual,   // ==
-    Greater,      // >
-    GreaterEqual, // >=
-    Less,         // <
-    LessEqual,    // <=
+    Bang,             // !
+    BangEqual,        // !=
+    Equal,            // =
+    EqualEqual,       // ==
+    EqualGreater,     // =>
+    Greater,          // >
+    GreaterEqual,     // >=
+    Less,             // <
+    LessEqual,        // <=
+    StarStar,         // **
+    QuestionQuestion, // ??
+    QuestionDot,      // ?.
+    PipeGreater,      // |>
+    Arrow,            // ->
+    DotDotDot,        // ...
 
     // literals.
     Identifier, // variables, function names, class names.
@@ -31,22 +43,31 @@ pub enum TokenType {
     Number,     // numbers : integers, floats.
 
     // keywords
-    True,   // true
-    False,  // false
-    Null,   // null
-    And,    // and
-    Or,     // or
-    Let,    // let
-    Class,  // class
-    Fn,     // fn
-    Return, // return
-    Super,  // super
-    This,   // this
-    If,     // if
-    Else,   // else
-    While,  // while
-    For,    // for
-    Print,  // print
+    True,     // true
+    False,    // false
+    Null,     // null
+    And,      // and
+    Or,       // or
+    Let,      // let
+    Class,    // class
+    Fn,       // fn
+    Return,   // return
+    Super,    // super
+    This,     // this
+    If,       // if
+    Else,     // else
+    While,    // while
+    For,      // for
+    Print,    // print
+    Enum,     // enum
+    Match,    // match
+    Assert,   // assert
+    Break,    // break
+    Continue, // continue
+    Do,       // do
+    Defer,    // defer
+    Try,      // try
+    Catch,    // catch
 
     Eof, // end of file.
 }
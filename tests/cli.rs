@@ -0,0 +1,286 @@
+//! Runs the splax binary on the scripts in `tests/fixtures`, checking
+//! what they print and the code they exit with.
+use std::{path::PathBuf, process::Command};
+
+/// Exit code of programs with syntax errors.
+const EXIT_COMPILE_ERROR: i32 = 65;
+
+/// Exit code of programs which stopped on an uncaught runtime error.
+const EXIT_RUNTIME_ERROR: i32 = 70;
+
+/// Runs a fixture script with logging turned off,
+/// returns what it printed and its exit code.
+/// # Arguments
+/// * `fixture` - file name of the script in `tests/fixtures`.
+fn run(fixture: &str) -> (String, i32) {
+    splax(&["run"], fixture)
+}
+
+/// Runs the splax binary with arguments followed by the path of a fixture,
+/// returns what it printed and its exit code.
+/// # Arguments
+/// * `args` - arguments before the path, starting with the command.
+/// * `fixture` - file name of the script in `tests/fixtures`.
+fn splax(args: &[&str], fixture: &str) -> (String, i32) {
+    splax_with_script_args(args, fixture, &[])
+}
+
+/// Runs the splax binary like `splax`, passing arguments to the script after `--`.
+/// # Arguments
+/// * `args` - arguments before the path, starting with the command.
+/// * `fixture` - file name of the script in `tests/fixtures`.
+/// * `script_args` - arguments for the script.
+fn splax_with_script_args(args: &[&str], fixture: &str, script_args: &[&str]) -> (String, i32) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", fixture]
+        .iter()
+        .collect();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_splax"));
+    command.args(args).arg(path).env("SPX_LOG", "off");
+    if !script_args.is_empty() {
+        command.arg("--").args(script_args);
+    }
+    let output = command.output().expect("failed to run the splax binary");
+
+    (
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        output.status.code().expect("splax was killed by a signal"),
+    )
+}
+
+#[test]
+fn optional_call_evaluates_callee_once() {
+    let (stdout, code) = run("optional_call.spx");
+    assert_eq!(stdout, "8\n1\nnull\nnull\n");
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn closures_share_their_scope() {
+    let (stdout, code) = run("closures.spx");
+    assert_eq!(stdout, "1\n2\n120\n");
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn cyclic_lists_print_and_compare() {
+    let (stdout, code) = run("cyclic_list.spx");
+    assert_eq!(stdout, "[1, 2, [...]]\ntrue\n");
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn repeat_overflow_is_a_runtime_error() {
+    let (stdout, code) = run("repeat_overflow.spx");
+    assert_eq!(
+        stdout,
+        "ababab\n\
+         'string.repeat' result would be longer than 1073741824 bytes.\n\
+         [line 8] Error '' : 'string.repeat' result would be longer than 1073741824 bytes.\n"
+    );
+    assert_eq!(code, EXIT_RUNTIME_ERROR);
+}
+
+#[test]
+fn defer_runs_on_runtime_errors() {
+    let (stdout, code) = run("defer_error.spx");
+    assert_eq!(
+        stdout,
+        "working\n\
+         cleanup\n\
+         [line 5] Error '' : unsupported operand type(s): 'Number' with a non 'Number'\n"
+    );
+    assert_eq!(code, EXIT_RUNTIME_ERROR);
+}
+
+#[test]
+fn tail_calls_inside_try_are_caught() {
+    for args in [&["run"][..], &["run", "--no-tco"]] {
+        let (stdout, code) = splax(args, "tail_call_in_try.spx");
+        assert_eq!(stdout, "caught\n-1\ndone\n", "splax {args:?}");
+        assert_eq!(code, 0, "splax {args:?}");
+    }
+}
+
+#[test]
+fn format_limits_are_catchable_errors() {
+    let (stdout, code) = run("format_limits.spx");
+    assert_eq!(
+        stdout,
+        "Number too large in '{:>99999999999}', it can be at most 1073741824.\n\
+         Number too large in '{:.99999999999}', it can be at most 1073741824.\n\
+         70002\n\
+         0.100\n\
+         Can only write whole numbers in another base, got 18446744073709552000.\n\
+         8000000000000000\n"
+    );
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn format_rejects_named_placeholders() {
+    let (stdout, code) = run("format_named.spx");
+    assert_eq!(
+        stdout,
+        "Named placeholders like '{secret}' aren't supported until splax has maps, pass the value by position instead.\n\
+         hello, world!\n"
+    );
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn max_depth_is_limited_by_the_stack() {
+    let (stdout, code) = splax(&["run", "--max-depth", "8192"], "max_depth.spx");
+    assert_eq!(stdout, "8000\ncaught\n");
+    assert_eq!(code, 0);
+
+    // limits the stack can't hold are rejected before running anything.
+    let (stdout, code) = splax(&["run", "--max-depth", "100000000"], "max_depth.spx");
+    assert_eq!(stdout, "");
+    assert_eq!(code, 2);
+}
+
+#[test]
+fn pipelines_call_any_expression() {
+    let (stdout, code) = run("pipeline.spx");
+    assert_eq!(
+        stdout,
+        "4\n8\n[a, b]\n6\n[line 12] Error '' : Called a non function type 'Number'.\n"
+    );
+    assert_eq!(code, EXIT_RUNTIME_ERROR);
+}
+
+#[test]
+fn optional_chains_short_circuit_entirely() {
+    let (stdout, code) = run("optional_chain.spx");
+    assert_eq!(stdout, "null\nnull\nnull\nfallback\nhi\n");
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn json_stringify_indent_is_optional() {
+    let (stdout, code) = run("json_stringify.spx");
+    assert_eq!(
+        stdout,
+        "[1,\"two\",null]\n[\n  1,\n  [\n    2\n  ]\n]\n\
+         'json.stringify' takes at most 2 arguments, got 3.\n\
+         'json.stringify' expects at least 1 arguments got 0.\n"
+    );
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn call_errors_point_at_the_call() {
+    let (stdout, code) = run("call_arity.spx");
+    assert_eq!(
+        stdout,
+        "[1, 2]\n[line 7] Error '' : Expected 2 arguments got 1.\n"
+    );
+    assert_eq!(code, EXIT_RUNTIME_ERROR);
+
+    let (stdout, code) = run("call_depth.spx");
+    assert_eq!(
+        stdout,
+        "[line 3] Error '' : Maximum recursion depth of 1000 exceeded calling 'forever'.\n\
+         Stack trace, most recent call first:\n    \
+         in 'forever' declared on line 2, repeated 1000 times\n"
+    );
+    assert_eq!(code, EXIT_RUNTIME_ERROR);
+}
+
+#[test]
+fn check_passes_well_typed_scripts() {
+    let (stdout, code) = splax(&["check"], "check_pass.spx");
+    assert_eq!(stdout, "No type errors found.\n");
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn check_reports_type_errors_without_running() {
+    let (stdout, code) = splax(&["check"], "check_fail.spx");
+    assert_eq!(
+        stdout,
+        "[line 6] Error '' : 'total' is declared as 'number' but initialised with a 'string'.\n\
+         [line 7] Error '' : Argument 1 of 'add' expects a 'number' but got a 'string'.\n\
+         [line 8] Error '' : 'flag' is declared as 'boolean' but initialised with a 'number'.\n"
+    );
+    assert_eq!(code, 1);
+}
+
+#[test]
+fn scripts_get_arguments_and_choose_their_exit_code() {
+    let (stdout, code) = splax_with_script_args(&["run"], "script_args.spx", &["a", "b c"]);
+    assert_eq!(stdout, "[a, b c]\n2\n");
+    assert_eq!(code, 3);
+}
+
+#[test]
+fn syntax_errors_exit_before_running() {
+    let (stdout, code) = run("syntax_error.spx");
+    assert_eq!(
+        stdout,
+        "[line 3] Error ' at ';' ' : Failed to parse let declaration\n"
+    );
+    assert_eq!(code, EXIT_COMPILE_ERROR);
+}
+
+#[test]
+fn missing_scripts_exit_with_one() {
+    let (stdout, code) = run("no_such_script.spx");
+    assert_eq!(stdout, "");
+    assert_eq!(code, 1);
+}
+
+#[test]
+fn tail_calls_only_grow_the_stack_without_tco() {
+    let (stdout, code) = run("tail_recursion.spx");
+    assert_eq!(stdout, "done\nfalse\n");
+    assert_eq!(code, 0);
+
+    let (stdout, code) = splax(&["run", "--no-tco"], "tail_recursion.spx");
+    assert_eq!(
+        stdout,
+        "[line 4] Error '' : Maximum recursion depth of 1000 exceeded calling 'count_down'.\n\
+         Stack trace, most recent call first:\n    \
+         in 'count_down' declared on line 2, repeated 1000 times\n"
+    );
+    assert_eq!(code, EXIT_RUNTIME_ERROR);
+}
+
+#[test]
+fn json_parse_reads_values_and_reports_positions() {
+    let (stdout, code) = run("json_parse.spx");
+    assert_eq!(
+        stdout,
+        "[1, -25, 0.5, true, null, [[]]]\n1\n[a b, , c]\ntrue\n\
+         Invalid JSON at line 1, column 1: invalid number '01'.\n\
+         Invalid JSON at line 1, column 1: invalid number '1.'.\n\
+         Invalid JSON at line 1, column 4: expected a value, found the end of the text.\n\
+         Invalid JSON at line 1, column 5: unexpected '2' after the value.\n\
+         Invalid JSON at line 1, column 1: unexpected 'nul'.\n"
+    );
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn regex_matches_replaces_and_splits() {
+    let (stdout, code) = run("regex.spx");
+    assert_eq!(
+        stdout,
+        "[2024-01-31, 2024, 01, 31]\n[1, 22, 333]\nhome:me work:you\n[a, b, c]\nnull\n\
+         Invalid pattern '(unclosed' at position 0: unclosed group.\n"
+    );
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn format_pads_aligns_and_converts() {
+    let (stdout, code) = run("format.spx");
+    assert_eq!(
+        stdout,
+        "0.30\nhello, world!\n[ab    |  cd  |    ef]\n***3.142\n\
+         +7 -0042 ff FF 10 00000101\n{braces}\n\
+         format template uses value 2 but only 1 values were given.\n\
+         format got 2 values but the template only uses 1.\n"
+    );
+    assert_eq!(code, 0);
+}